@@ -9,8 +9,9 @@ use binum::UInt;
 fn gcd(a: UInt, b: UInt) -> UInt {
     let mut ma = cmp::max(a.clone(), b.clone());
     let mut mi = cmp::min(a, b);
-    while mi > UInt::from(0) {
-        (ma, mi) = (mi.clone(), ma % mi);
+    while mi > UInt::from(0u8) {
+        let r = &ma % &mi;
+        (ma, mi) = (mi, r);
     }
     ma
 }
@@ -27,20 +28,18 @@ fn main() {
         .next()
         .expect("too few input lines")
         .expect("too few input lines")
-        .parse::<u64>()
+        .parse::<UInt>()
         .expect("please input number");
-    let a = UInt::from(a);
     stdout.write_all(b"b: ").expect("failed to write message");
     stdout.flush().expect("failed to flush stdout");
     let b = lines
         .next()
         .expect("too few input lines")
         .expect("too few input lines")
-        .parse::<u64>()
+        .parse::<UInt>()
         .expect("please input number");
-    let b = UInt::from(b);
     let c = gcd(a, b);
-    let message = format!("gcd(a, b) is {}\n", u64::from(c));
+    let message = format!("gcd(a, b) is {c}\n");
     stdout
         .write_all(message.as_bytes())
         .expect("failed to write message");
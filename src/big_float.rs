@@ -0,0 +1,650 @@
+use std::cmp::Ordering::*;
+use std::num::IntErrorKind;
+use std::{cmp, error, fmt, ops};
+
+use crate::float::{round_div, round_shifted};
+use crate::{Rational, RoundingMode, UInt};
+
+// decimal exponents beyond this would overflow the binary exponent
+const MAX_DECIMAL_SCALE: u64 = i64::MAX as u64 / 8;
+// decimal strings are cut to this many digits, more would not fit in memory
+const MAX_DECIMAL_DIGITS: usize = 1 << 40;
+
+// `mantissa * 2^exponent` rounded to `precision` bits, the mantissa
+// is kept odd so every value has one representation
+#[derive(Clone)]
+pub struct BigFloat {
+    _negative: bool,
+    _mantissa: UInt,
+    _exponent: i64,
+    _precision: usize,
+}
+
+impl BigFloat {
+    pub fn zero(precision: usize) -> Self {
+        check_precision(precision);
+        BigFloat {
+            _negative: false,
+            _mantissa: UInt::new([]),
+            _exponent: 0,
+            _precision: precision,
+        }
+    }
+
+    pub fn from_uint(n: &UInt, precision: usize, mode: RoundingMode) -> Self {
+        BigFloat::round(false, n.clone(), 0, false, precision, mode)
+    }
+
+    pub fn from_rational(q: &Rational, precision: usize, mode: RoundingMode) -> Self {
+        BigFloat::from_ratio(q.is_negative(), q.numer(), q.denom(), precision, mode)
+    }
+
+    pub fn to_rational(&self) -> Rational {
+        let one = UInt::from(1u8);
+        let shift = self._exponent.unsigned_abs() as usize;
+        if self._exponent >= 0 {
+            Rational::new(self._negative, &self._mantissa << shift, one)
+        } else {
+            Rational::new(self._negative, self._mantissa.clone(), one << shift)
+        }
+    }
+
+    pub fn precision(&self) -> usize {
+        self._precision
+    }
+
+    pub fn mantissa(&self) -> &UInt {
+        &self._mantissa
+    }
+
+    pub fn exponent(&self) -> i64 {
+        self._exponent
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self._negative
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self._mantissa.max_bit_digit() == 0
+    }
+
+    pub fn abs(&self) -> Self {
+        BigFloat {
+            _negative: false,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_precision(&self, precision: usize, mode: RoundingMode) -> Self {
+        BigFloat::round(
+            self._negative,
+            self._mantissa.clone(),
+            self._exponent,
+            false,
+            precision,
+            mode,
+        )
+    }
+
+    // the results below have the larger precision of the operands
+
+    pub fn add_with(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        let precision = cmp::max(self._precision, rhs._precision);
+        if rhs.is_zero() {
+            return self.with_precision(precision, mode);
+        }
+        if self.is_zero() {
+            return rhs.with_precision(precision, mode);
+        }
+        let (big, small) = match self.cmp_abs(rhs) {
+            Less => (rhs, self),
+            _ => (self, rhs),
+        };
+        // an operand far below the last bit only matters for its sign,
+        // so it can be replaced by a smaller power of two
+        let gap_bound = big.top() - precision as i64 - 3;
+        let (small_mantissa, small_exponent) = if small.top() < gap_bound {
+            (UInt::from(1u8), gap_bound - 1)
+        } else {
+            (small._mantissa.clone(), small._exponent)
+        };
+        let e = cmp::min(big._exponent, small_exponent);
+        let a = &big._mantissa << (big._exponent - e) as usize;
+        let b = small_mantissa << (small_exponent - e) as usize;
+        if big._negative == small._negative {
+            BigFloat::round(big._negative, a + b, e, false, precision, mode)
+        } else if a == b {
+            BigFloat::zero(precision)
+        } else {
+            BigFloat::round(big._negative, a - b, e, false, precision, mode)
+        }
+    }
+
+    pub fn sub_with(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        self.add_with(&-rhs.clone(), mode)
+    }
+
+    pub fn mul_with(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        let precision = cmp::max(self._precision, rhs._precision);
+        BigFloat::round(
+            self._negative != rhs._negative,
+            &self._mantissa * &rhs._mantissa,
+            self._exponent + rhs._exponent,
+            false,
+            precision,
+            mode,
+        )
+    }
+
+    pub fn div_with(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let precision = cmp::max(self._precision, rhs._precision);
+        let (n, d) = (&self._mantissa, &rhs._mantissa);
+        let k = (precision + 2 + d.max_bit_digit()).saturating_sub(n.max_bit_digit());
+        let (q, r) = (n << k).div_rem(d);
+        BigFloat::round(
+            self._negative != rhs._negative,
+            q,
+            self._exponent - rhs._exponent - k as i64,
+            r.max_bit_digit() > 0,
+            precision,
+            mode,
+        )
+    }
+
+    pub fn sqrt_with(&self, mode: RoundingMode) -> Self {
+        if self._negative {
+            panic!("attempt to take the square root of a negative number");
+        }
+        if self.is_zero() {
+            return self.clone();
+        }
+        // at least precision + 2 root bits, with an even exponent
+        let m = &self._mantissa;
+        let mut k = (2 * (self._precision + 2)).saturating_sub(m.max_bit_digit());
+        if (self._exponent - k as i64) % 2 != 0 {
+            k += 1;
+        }
+        let (root, rem) = (m << k).sqrt_rem();
+        BigFloat::round(
+            false,
+            root,
+            (self._exponent - k as i64) / 2,
+            rem.max_bit_digit() > 0,
+            self._precision,
+            mode,
+        )
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.sqrt_with(RoundingMode::NearestTiesToEven)
+    }
+
+    // accepts decimals like "-12.5", ".5" and "1.25e-3"
+    pub fn parse(
+        src: &str,
+        precision: usize,
+        mode: RoundingMode,
+    ) -> Result<Self, ParseBigFloatError> {
+        if src.is_empty() {
+            return Err(ParseBigFloatError::new(BigFloatErrorKind::Empty));
+        }
+        let invalid = ParseBigFloatError::new(BigFloatErrorKind::Invalid);
+        let out_of_range = ParseBigFloatError::new(BigFloatErrorKind::ExponentOutOfRange);
+        let (negative, body) = match src.as_bytes()[0] {
+            b'-' => (true, &src[1..]),
+            b'+' => (false, &src[1..]),
+            _ => (false, src),
+        };
+        let (digits, exp) = match body.split_once(['e', 'E']) {
+            Some((digits, exp)) => {
+                let exp = exp.parse::<i64>().map_err(|e| match e.kind() {
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => out_of_range.clone(),
+                    _ => invalid.clone(),
+                })?;
+                (digits, exp)
+            }
+            None => (body, 0),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        let all_digits = |t: &str| t.bytes().all(|b| b.is_ascii_digit());
+        if int.len() + frac.len() == 0 || !all_digits(int) || !all_digits(frac) {
+            return Err(invalid);
+        }
+        let n: UInt = format!("0{int}{frac}").parse().map_err(|_| invalid)?;
+        // value == n * 10^scale
+        let scale = i64::try_from(frac.len())
+            .ok()
+            .and_then(|len| exp.checked_sub(len))
+            .filter(|scale| scale.unsigned_abs() <= MAX_DECIMAL_SCALE)
+            .ok_or(out_of_range)?;
+        let k = scale.unsigned_abs();
+        let bits = n.max_bit_digit();
+        // 5^k > 2^(2k) exceeds both n and 2^(precision + 1) past this, so
+        // the value is neither exact nor a tie and the bounds settle
+        if k <= (precision + 1 + bits) as u64 / 2 {
+            let p = UInt::from(10u8).pow(UInt::from(k));
+            return Ok(if scale >= 0 {
+                BigFloat::round(negative, n * p, 0, false, precision, mode)
+            } else {
+                BigFloat::from_ratio(negative, &n, &p, precision, mode)
+            });
+        }
+        let x = BigFloat::from_uint(&n, bits.max(1), RoundingMode::TowardZero);
+        Ok(x.round_scaled(scale, precision, |bound| {
+            let bound = if negative { -bound } else { bound };
+            bound.with_precision(precision, mode)
+        }))
+    }
+
+    // scientific notation with `digits` significant digits, like "1.250e-3"
+    pub fn to_decimal_string(&self, digits: usize, mode: RoundingMode) -> String {
+        assert!(digits > 0, "at least one digit is required");
+        let digits = digits.min(MAX_DECIMAL_DIGITS);
+        if self.is_zero() {
+            let zeros = "0".repeat(digits - 1);
+            return if digits > 1 {
+                format!("0.{zeros}e0")
+            } else {
+                "0e0".to_string()
+            };
+        }
+        let (m, e) = (&self._mantissa, self._exponent);
+        // the exact value has no more significant digits than this, so
+        // rounding to more of them only appends zeros
+        let exact = m.max_bit_digit().saturating_add(e.unsigned_abs() as usize);
+        let rounded = digits.min(exact);
+        // floor(log10(value)), estimated from the binary exponent
+        let mut d10 = (self.top() as f64 * std::f64::consts::LOG10_2).floor() as i64;
+        let ten = UInt::from(10u8);
+        let lower = ten.pow(UInt::from(rounded - 1));
+        let upper = &lower * &ten;
+        let n = loop {
+            // value * 10^(rounded - 1 - d10), rounded to an integer
+            let scale = rounded as i64 - 1 - d10;
+            let n = self.round_scaled_to_uint(scale, mode);
+            if n >= upper {
+                d10 += 1;
+            } else if n < lower {
+                d10 -= 1;
+            } else {
+                break n;
+            }
+        };
+        let s = format!("{n}{}", "0".repeat(digits - rounded));
+        let sign = if self._negative { "-" } else { "" };
+        if s.len() > 1 {
+            format!("{sign}{}.{}e{d10}", &s[..1], &s[1..])
+        } else {
+            format!("{sign}{s}e{d10}")
+        }
+    }
+
+    // pi by Machin's formula, pi = 16 atan(1/5) - 4 atan(1/239)
+    pub fn pi(precision: usize) -> Self {
+        check_precision(precision);
+        let guard = 64 + usize::BITS as usize - precision.leading_zeros() as usize;
+        let bits = precision + guard;
+        let a = atan_inv(5, bits) << 4usize;
+        let b = atan_inv(239, bits) << 2usize;
+        // the truncation error is far below the guard bits
+        BigFloat::round(
+            false,
+            a - b,
+            -(bits as i64),
+            true,
+            precision,
+            RoundingMode::NearestTiesToEven,
+        )
+    }
+
+    // e as the sum of 1 / k!
+    pub fn e(precision: usize) -> Self {
+        check_precision(precision);
+        let guard = 64 + usize::BITS as usize - precision.leading_zeros() as usize;
+        let bits = precision + guard;
+        let mut term = UInt::from(1u8) << bits;
+        let mut sum = UInt::new([]);
+        let mut k = 1u64;
+        while term.max_bit_digit() > 0 {
+            sum += &term;
+            term /= UInt::from(k);
+            k += 1;
+        }
+        BigFloat::round(
+            false,
+            sum,
+            -(bits as i64),
+            true,
+            precision,
+            RoundingMode::NearestTiesToEven,
+        )
+    }
+
+    // `n / d` rounded to `precision` bits
+    fn from_ratio(
+        negative: bool,
+        n: &UInt,
+        d: &UInt,
+        precision: usize,
+        mode: RoundingMode,
+    ) -> Self {
+        check_precision(precision);
+        // at least precision + 2 quotient bits, the rest goes to sticky
+        let k = (precision + 2 + d.max_bit_digit()) as i64 - n.max_bit_digit() as i64;
+        let (quot, rem) = if k >= 0 {
+            (n << k as usize).div_rem(d)
+        } else {
+            n.div_rem(&(d << k.unsigned_abs() as usize))
+        };
+        let sticky = rem.max_bit_digit() > 0;
+        BigFloat::round(negative, quot, -k, sticky, precision, mode)
+    }
+
+    // |self| * 10^scale lies between the two bounds, which are computed
+    // with `precision` bits and rounded away from the exact value
+    fn scaled_bounds(&self, scale: i64, precision: usize) -> (Self, Self) {
+        let k = scale.unsigned_abs();
+        let lo = pow10(k, precision, RoundingMode::TowardZero);
+        let hi = pow10(k, precision, RoundingMode::TowardPositive);
+        let x = self.abs();
+        if scale >= 0 {
+            (
+                x.mul_with(&lo, RoundingMode::TowardZero),
+                x.mul_with(&hi, RoundingMode::TowardPositive),
+            )
+        } else {
+            (
+                x.div_with(&hi, RoundingMode::TowardZero),
+                x.div_with(&lo, RoundingMode::TowardPositive),
+            )
+        }
+    }
+
+    // rounds |self| * 10^scale through `round`, doubling the working
+    // precision until both bounds round alike; `round` must be monotonic,
+    // and the exact value must not be one of the points where it jumps
+    fn round_scaled<T: PartialEq>(
+        &self,
+        scale: i64,
+        precision: usize,
+        round: impl Fn(Self) -> T,
+    ) -> T {
+        let mut w = precision + 64;
+        loop {
+            let (lo, hi) = self.scaled_bounds(scale, w);
+            let (lo, hi) = (round(lo), round(hi));
+            if lo == hi {
+                return lo;
+            }
+            w *= 2;
+        }
+    }
+
+    // |self| * 10^scale rounded to an integer, for a value of our sign
+    fn round_scaled_to_uint(&self, scale: i64, mode: RoundingMode) -> UInt {
+        let (m, e) = (&self._mantissa, self._exponent);
+        let k = scale.unsigned_abs();
+        // m * 2^e * 10^scale is an integer or a half integer only when
+        // these hold, and then the exact computation stays small
+        let exact = if scale >= 0 {
+            e.saturating_add(scale) >= -1
+        } else {
+            k <= m.max_bit_digit() as u64 / 2
+        };
+        if exact {
+            let p = UInt::from(10u8).pow(UInt::from(k));
+            let (mut num, mut den) = (m.clone(), UInt::from(1u8));
+            if scale >= 0 {
+                num *= p;
+            } else {
+                den = p;
+            }
+            if e >= 0 {
+                num <<= e as usize;
+            } else {
+                den <<= e.unsigned_abs() as usize;
+            }
+            return round_div(&num, &den, self._negative, mode);
+        }
+        let precision = m.max_bit_digit() + 64;
+        self.round_scaled(scale, precision, |bound| {
+            bound.round_to_uint(self._negative, mode)
+        })
+    }
+
+    // rounds to an integer under `mode`, for a value of sign `negative`
+    fn round_to_uint(&self, negative: bool, mode: RoundingMode) -> UInt {
+        if self._exponent >= 0 {
+            return &self._mantissa << self._exponent as usize;
+        }
+        if self.top() < -1 {
+            // a nonzero value below one half
+            return round_shifted(&UInt::from(1u8), 2, true, negative, mode);
+        }
+        let shift = self._exponent.unsigned_abs() as usize;
+        round_shifted(&self._mantissa, shift, false, negative, mode)
+    }
+
+    // the exponent of the leading bit
+    fn top(&self) -> i64 {
+        self._exponent + self._mantissa.max_bit_digit() as i64 - 1
+    }
+
+    fn cmp_abs(&self, other: &Self) -> cmp::Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Equal,
+            (true, false) => return Less,
+            (false, true) => return Greater,
+            _ => {}
+        }
+        match self.top().cmp(&other.top()) {
+            Equal => {}
+            ord => return ord,
+        }
+        // same leading bit, so the shifts are bounded by the mantissas
+        let e = cmp::min(self._exponent, other._exponent);
+        let a = &self._mantissa << (self._exponent - e) as usize;
+        let b = &other._mantissa << (other._exponent - e) as usize;
+        a.cmp(&b)
+    }
+
+    // rounds `(significand + sticky) * 2^exponent` to `precision` bits
+    fn round(
+        negative: bool,
+        significand: UInt,
+        exponent: i64,
+        sticky: bool,
+        precision: usize,
+        mode: RoundingMode,
+    ) -> Self {
+        check_precision(precision);
+        let bits = significand.max_bit_digit();
+        if bits == 0 {
+            return BigFloat::zero(precision);
+        }
+        // widen first, so `sticky` stays below the last kept bit
+        let (significand, exponent) = if bits < precision {
+            let k = precision - bits;
+            (significand << k, exponent - k as i64)
+        } else {
+            (significand, exponent)
+        };
+        let shift = significand.max_bit_digit() - precision;
+        let m = round_shifted(&significand, shift, sticky, negative, mode);
+        BigFloat::normalized(negative, m, exponent + shift as i64, precision)
+    }
+
+    // strips trailing zero bits from the mantissa
+    fn normalized(negative: bool, mantissa: UInt, exponent: i64, precision: usize) -> Self {
+        let zeros = mantissa.trailing_zeros().unwrap_or(0);
+        let is_zero = mantissa.max_bit_digit() == 0;
+        BigFloat {
+            _negative: negative && !is_zero,
+            _mantissa: mantissa >> zeros,
+            _exponent: if is_zero { 0 } else { exponent + zeros as i64 },
+            _precision: precision,
+        }
+    }
+}
+
+fn check_precision(precision: usize) {
+    if precision == 0 {
+        panic!("precision must be positive");
+    }
+}
+
+// 10^k rounded to `precision` bits, by squaring and multiplying under
+// `mode`, which all round the same way for positive values
+fn pow10(k: u64, precision: usize, mode: RoundingMode) -> BigFloat {
+    let ten = BigFloat::from_uint(&UInt::from(10u8), precision, mode);
+    let mut res = BigFloat::from_uint(&UInt::from(1u8), precision, mode);
+    for i in (0..u64::BITS - k.leading_zeros()).rev() {
+        res = res.mul_with(&res, mode);
+        if k >> i & 1 == 1 {
+            res = res.mul_with(&ten, mode);
+        }
+    }
+    res
+}
+
+// atan(1 / x) * 2^bits, truncated term by term
+fn atan_inv(x: u64, bits: usize) -> UInt {
+    let x2 = UInt::from(x * x);
+    let mut power = (UInt::from(1u8) << bits) / UInt::from(x);
+    let mut sum = UInt::new([]);
+    let mut k = 0u64;
+    while power.max_bit_digit() > 0 {
+        let term = &power / &UInt::from(2 * k + 1);
+        if k.is_multiple_of(2) {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+        power /= &x2;
+        k += 1;
+    }
+    sum
+}
+
+impl fmt::Debug for BigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BigFloat")
+            .field("negative", &self._negative)
+            .field("mantissa", &self._mantissa)
+            .field("exponent", &self._exponent)
+            .field("precision", &self._precision)
+            .finish()
+    }
+}
+
+impl fmt::Display for BigFloat {
+    // enough significant digits to tell apart neighbors at this precision
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = (self._precision as f64 * std::f64::consts::LOG10_2).ceil() as usize + 1;
+        let s = self
+            .abs()
+            .to_decimal_string(digits, RoundingMode::NearestTiesToEven);
+        f.pad_integral(!self._negative, "", &s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigFloatErrorKind {
+    Empty,
+    Invalid,
+    ExponentOutOfRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigFloatError {
+    kind: BigFloatErrorKind,
+}
+
+impl ParseBigFloatError {
+    fn new(kind: BigFloatErrorKind) -> Self {
+        ParseBigFloatError { kind }
+    }
+
+    pub fn kind(&self) -> &BigFloatErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseBigFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            BigFloatErrorKind::Empty => "cannot parse float from empty string",
+            BigFloatErrorKind::Invalid => "invalid float literal",
+            BigFloatErrorKind::ExponentOutOfRange => "exponent out of range",
+        };
+        f.write_str(message)
+    }
+}
+
+impl error::Error for ParseBigFloatError {}
+
+// compares values, regardless of precision
+impl cmp::PartialEq for BigFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self._negative == other._negative
+            && self._mantissa == other._mantissa
+            && self._exponent == other._exponent
+    }
+}
+
+impl cmp::Eq for BigFloat {}
+
+impl cmp::Ord for BigFloat {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self._negative, other._negative) {
+            (false, true) => Greater,
+            (true, false) => Less,
+            (false, false) => self.cmp_abs(other),
+            (true, true) => other.cmp_abs(self),
+        }
+    }
+}
+
+impl cmp::PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ops::Neg for BigFloat {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        BigFloat {
+            _negative: !self._negative && !self.is_zero(),
+            ..self
+        }
+    }
+}
+
+macro_rules! impl_arith {
+    ($($imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident, $with:ident;)*) => {$(
+        impl ops::$imp for BigFloat {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$with(&rhs, RoundingMode::NearestTiesToEven)
+            }
+        }
+
+        impl ops::$imp_assign for BigFloat {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = self.$with(&rhs, RoundingMode::NearestTiesToEven);
+            }
+        }
+    )*};
+}
+
+impl_arith! {
+    Add, add, AddAssign, add_assign, add_with;
+    Sub, sub, SubAssign, sub_assign, sub_with;
+    Mul, mul, MulAssign, mul_assign, mul_with;
+    Div, div, DivAssign, div_assign, div_with;
+}
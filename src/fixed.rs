@@ -0,0 +1,231 @@
+use std::str::FromStr;
+use std::{fmt, ops};
+
+use crate::float::{round_div, round_shifted};
+use crate::{Int, ParseRationalError, Rational, RoundingMode, UInt};
+
+// signed fixed point number `raw * 2^-FRAC` with an unbounded integer part
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed<const FRAC: usize> {
+    _raw: Int,
+}
+
+impl<const FRAC: usize> Fixed<FRAC> {
+    pub const FRAC: usize = FRAC;
+
+    pub fn from_raw(raw: Int) -> Self {
+        Fixed { _raw: raw }
+    }
+
+    pub fn raw(&self) -> &Int {
+        &self._raw
+    }
+
+    pub fn into_raw(self) -> Int {
+        self._raw
+    }
+
+    pub fn from_rational(q: &Rational, mode: RoundingMode) -> Self {
+        let m = round_div(&(q.numer() << FRAC), q.denom(), q.is_negative(), mode);
+        Fixed::from_raw(Int::new(q.is_negative(), m))
+    }
+
+    pub fn to_rational(&self) -> Rational {
+        let (negative, m) = self._raw.clone().into_parts();
+        Rational::new(negative, m, UInt::from(1u8) << FRAC)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self._raw.is_negative()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self._raw.is_zero()
+    }
+
+    pub fn abs(&self) -> Self {
+        Fixed::from_raw(self._raw.abs())
+    }
+
+    // the integer part, rounded toward negative infinity
+    pub fn floor(&self) -> Int {
+        self._raw.clone() >> UInt::from(FRAC)
+    }
+
+    pub fn mul_with(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        let negative = self.is_negative() != rhs.is_negative();
+        let product = self._raw.magnitude() * rhs._raw.magnitude();
+        let m = round_shifted(&product, FRAC, false, negative, mode);
+        Fixed::from_raw(Int::new(negative, m))
+    }
+
+    pub fn div_with(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let q = self.to_rational() / rhs.to_rational();
+        Fixed::from_rational(&q, mode)
+    }
+
+    // reduces into a two's complement register of `width` bits
+    // (integer, fraction and sign bits together), wrapping around
+    pub fn wrapping_to(&self, width: usize) -> Self {
+        assert!(width > 0, "width must be positive");
+        let modulus = UInt::from(1u8) << width;
+        let (negative, m) = self._raw.clone().into_parts();
+        let mut r = m % &modulus;
+        if negative && r.max_bit_digit() > 0 {
+            r = &modulus - &r;
+        }
+        // the upper half of the register holds negative values
+        if r.max_bit_digit() == width {
+            Fixed::from_raw(Int::new(true, modulus - r))
+        } else {
+            Fixed::from_raw(Int::new(false, r))
+        }
+    }
+
+    // clamps into the range of a two's complement register of `width` bits
+    pub fn saturating_to(&self, width: usize) -> Self {
+        assert!(width > 0, "width must be positive");
+        let limit = UInt::from(1u8) << (width - 1);
+        let (negative, m) = self._raw.clone().into_parts();
+        let m = if negative {
+            m.min(limit)
+        } else {
+            m.min(limit - 1u64)
+        };
+        Fixed::from_raw(Int::new(negative, m))
+    }
+}
+
+impl<const FRAC: usize> From<Int> for Fixed<FRAC> {
+    fn from(n: Int) -> Self {
+        Fixed::from_raw(n << UInt::from(FRAC))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {$(
+        impl<const FRAC: usize> From<$t> for Fixed<FRAC> {
+            fn from(n: $t) -> Self {
+                Fixed::from(Int::from(n))
+            }
+        }
+    )*};
+}
+
+impl_from_int!(UInt, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<const FRAC: usize> fmt::Debug for Fixed<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fixed")
+            .field("raw", &self._raw)
+            .field("frac", &FRAC)
+            .finish()
+    }
+}
+
+impl<const FRAC: usize> fmt::Display for Fixed<FRAC> {
+    // exact decimal expansion, or rounded to the requested precision
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = self._raw.magnitude();
+        // m / 2^FRAC == m * 5^FRAC / 10^FRAC
+        let scaled = m * &UInt::from(5u8).pow(FRAC as u32);
+        let (digits, frac_len) = match f.precision() {
+            Some(p) if p < FRAC => {
+                let shift = UInt::from(10u8).pow((FRAC - p) as u32);
+                let rounded = round_div(&scaled, &shift, false, RoundingMode::NearestTiesToEven);
+                (rounded.to_string(), p)
+            }
+            Some(p) => (format!("{scaled}{}", "0".repeat(p - FRAC)), p),
+            None if self.is_zero() => ("0".to_string(), 0),
+            None => {
+                let s = scaled.to_string();
+                // strip trailing zeros of the fraction
+                let zeros = s.len() - s.trim_end_matches('0').len();
+                let keep = zeros.min(FRAC);
+                (s[..s.len() - keep].to_string(), FRAC - keep)
+            }
+        };
+        let digits = format!("{digits:0>width$}", width = frac_len + 1);
+        let (int, frac) = digits.split_at(digits.len() - frac_len);
+        let s = if frac.is_empty() {
+            int.to_string()
+        } else {
+            format!("{int}.{frac}")
+        };
+        let non_negative = !self.is_negative() || s.bytes().all(|b| b == b'0' || b == b'.');
+        f.pad_integral(non_negative, "", &s)
+    }
+}
+
+impl<const FRAC: usize> FromStr for Fixed<FRAC> {
+    type Err = ParseRationalError;
+    // rounds decimals and fractions to the nearest value, ties to even
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let q: Rational = s.parse()?;
+        Ok(Fixed::from_rational(&q, RoundingMode::NearestTiesToEven))
+    }
+}
+
+impl<const FRAC: usize> ops::Neg for Fixed<FRAC> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Fixed::from_raw(-self._raw)
+    }
+}
+
+impl<const FRAC: usize> ops::Add for Fixed<FRAC> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Fixed::from_raw(self._raw + rhs._raw)
+    }
+}
+
+impl<const FRAC: usize> ops::AddAssign for Fixed<FRAC> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<const FRAC: usize> ops::Sub for Fixed<FRAC> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fixed::from_raw(self._raw - rhs._raw)
+    }
+}
+
+impl<const FRAC: usize> ops::SubAssign for Fixed<FRAC> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+// multiplication and division round toward negative infinity,
+// like an arithmetic right shift
+impl<const FRAC: usize> ops::Mul for Fixed<FRAC> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_with(&rhs, RoundingMode::TowardNegative)
+    }
+}
+
+impl<const FRAC: usize> ops::MulAssign for Fixed<FRAC> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_with(&rhs, RoundingMode::TowardNegative);
+    }
+}
+
+impl<const FRAC: usize> ops::Div for Fixed<FRAC> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_with(&rhs, RoundingMode::TowardNegative)
+    }
+}
+
+impl<const FRAC: usize> ops::DivAssign for Fixed<FRAC> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_with(&rhs, RoundingMode::TowardNegative);
+    }
+}
@@ -0,0 +1,311 @@
+use std::{cmp, fmt, ops};
+
+use crate::{Boolean, TryFromUIntError, UInt};
+
+// unsigned integer of exactly `BITS` bits,
+// every operation wraps around at 2^BITS
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FixedUInt<const BITS: usize> {
+    _binary: [Boolean; BITS],
+}
+
+pub type U8 = FixedUInt<8>;
+pub type U16 = FixedUInt<16>;
+pub type U32 = FixedUInt<32>;
+pub type U64 = FixedUInt<64>;
+pub type U128 = FixedUInt<128>;
+pub type U256 = FixedUInt<256>;
+
+impl<const BITS: usize> FixedUInt<BITS> {
+    pub const BITS: usize = BITS;
+    pub const ZERO: Self = FixedUInt {
+        _binary: [Boolean::False; BITS],
+    };
+    pub const MAX: Self = FixedUInt {
+        _binary: [Boolean::True; BITS],
+    };
+
+    pub fn new(binary: [Boolean; BITS]) -> Self {
+        FixedUInt { _binary: binary }
+    }
+
+    pub fn binary(&self) -> &[Boolean; BITS] {
+        &self._binary
+    }
+
+    // keeps the lowest BITS bits of `n`
+    pub fn wrapping_from(n: &UInt) -> Self {
+        let mut binary = [Boolean::False; BITS];
+        for (b, bit) in binary.iter_mut().zip(n.bits()) {
+            *b = bit;
+        }
+        FixedUInt { _binary: binary }
+    }
+
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let mut binary = [Boolean::False; BITS];
+        let mut carry = Boolean::False;
+        for (i, sum) in binary.iter_mut().enumerate() {
+            let (a, b, c) = (self._binary[i], rhs._binary[i], carry);
+            *sum = a ^ b ^ c;
+            carry = (a & b) | (b & c) | (c & a);
+        }
+        (FixedUInt { _binary: binary }, carry.into())
+    }
+
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut binary = [Boolean::False; BITS];
+        let mut borrow = Boolean::False;
+        for (i, diff) in binary.iter_mut().enumerate() {
+            let (a, b, c) = (self._binary[i], rhs._binary[i], borrow);
+            *diff = a ^ b ^ c;
+            borrow = (!a & b) | (!(a ^ b) & c);
+        }
+        (FixedUInt { _binary: binary }, borrow.into())
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        Self::ZERO.overflowing_sub(self).0
+    }
+
+    pub fn rotate_left(self, n: usize) -> Self {
+        if BITS == 0 {
+            return self;
+        }
+        let mut binary = [Boolean::False; BITS];
+        for (i, &b) in self._binary.iter().enumerate() {
+            binary[(i + n % BITS) % BITS] = b;
+        }
+        FixedUInt { _binary: binary }
+    }
+
+    pub fn rotate_right(self, n: usize) -> Self {
+        if BITS == 0 {
+            return self;
+        }
+        self.rotate_left(BITS - n % BITS)
+    }
+
+    fn bitwise<F>(self, rhs: Self, f: F) -> Self
+    where
+        F: Fn(Boolean, Boolean) -> Boolean,
+    {
+        let mut binary = self._binary;
+        for (b1, &b2) in binary.iter_mut().zip(rhs._binary.iter()) {
+            *b1 = f(*b1, b2);
+        }
+        FixedUInt { _binary: binary }
+    }
+}
+
+impl<const BITS: usize> Default for FixedUInt<BITS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const BITS: usize> From<FixedUInt<BITS>> for UInt {
+    fn from(n: FixedUInt<BITS>) -> Self {
+        UInt::new(n._binary)
+    }
+}
+
+impl<const BITS: usize> TryFrom<UInt> for FixedUInt<BITS> {
+    type Error = TryFromUIntError;
+    fn try_from(n: UInt) -> Result<Self, Self::Error> {
+        if n.max_bit_digit() > BITS {
+            return Err(TryFromUIntError(()));
+        }
+        Ok(Self::wrapping_from(&n))
+    }
+}
+
+impl<const BITS: usize> fmt::Debug for FixedUInt<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedUInt")
+            .field("binary", &self._binary)
+            .finish()
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($imp:ident),*) => {$(
+        impl<const BITS: usize> fmt::$imp for FixedUInt<BITS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$imp::fmt(&UInt::from(*self), f)
+            }
+        }
+    )*};
+}
+
+impl_fmt!(Display, Binary, Octal, LowerHex, UpperHex);
+
+impl<const BITS: usize> cmp::Ord for FixedUInt<BITS> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let bin1 = self._binary.iter().rev().map(|&b| b as u8);
+        let bin2 = other._binary.iter().rev().map(|&b| b as u8);
+        bin1.cmp(bin2)
+    }
+}
+
+impl<const BITS: usize> cmp::PartialOrd for FixedUInt<BITS> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize> ops::Not for FixedUInt<BITS> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        FixedUInt {
+            _binary: self._binary.map(|b| !b),
+        }
+    }
+}
+
+impl<const BITS: usize> ops::BitAnd for FixedUInt<BITS> {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, |b1, b2| b1 & b2)
+    }
+}
+
+impl<const BITS: usize> ops::BitAndAssign for FixedUInt<BITS> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl<const BITS: usize> ops::BitOr for FixedUInt<BITS> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, |b1, b2| b1 | b2)
+    }
+}
+
+impl<const BITS: usize> ops::BitOrAssign for FixedUInt<BITS> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl<const BITS: usize> ops::BitXor for FixedUInt<BITS> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, |b1, b2| b1 ^ b2)
+    }
+}
+
+impl<const BITS: usize> ops::BitXorAssign for FixedUInt<BITS> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+// bits shifted out are dropped, so shifting by BITS or more gives zero
+impl<const BITS: usize> ops::Shl<usize> for FixedUInt<BITS> {
+    type Output = Self;
+    fn shl(self, rhs: usize) -> Self::Output {
+        let mut binary = [Boolean::False; BITS];
+        for (b, &src) in binary.iter_mut().skip(rhs).zip(self._binary.iter()) {
+            *b = src;
+        }
+        FixedUInt { _binary: binary }
+    }
+}
+
+impl<const BITS: usize> ops::ShlAssign<usize> for FixedUInt<BITS> {
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const BITS: usize> ops::Shr<usize> for FixedUInt<BITS> {
+    type Output = Self;
+    fn shr(self, rhs: usize) -> Self::Output {
+        let mut binary = [Boolean::False; BITS];
+        for (b, &src) in binary.iter_mut().zip(self._binary.iter().skip(rhs)) {
+            *b = src;
+        }
+        FixedUInt { _binary: binary }
+    }
+}
+
+impl<const BITS: usize> ops::ShrAssign<usize> for FixedUInt<BITS> {
+    fn shr_assign(&mut self, rhs: usize) {
+        *self = *self >> rhs;
+    }
+}
+
+impl<const BITS: usize> ops::Add for FixedUInt<BITS> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.overflowing_add(rhs).0
+    }
+}
+
+impl<const BITS: usize> ops::AddAssign for FixedUInt<BITS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const BITS: usize> ops::Sub for FixedUInt<BITS> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl<const BITS: usize> ops::SubAssign for FixedUInt<BITS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const BITS: usize> ops::Mul for FixedUInt<BITS> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut res = Self::ZERO;
+        for (i, &b) in rhs._binary.iter().enumerate() {
+            if b.into() {
+                res += self << i;
+            }
+        }
+        res
+    }
+}
+
+impl<const BITS: usize> ops::MulAssign for FixedUInt<BITS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const BITS: usize> ops::Div for FixedUInt<BITS> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        let (q, _) = UInt::from(self).div_rem(&UInt::from(rhs));
+        Self::wrapping_from(&q)
+    }
+}
+
+impl<const BITS: usize> ops::DivAssign for FixedUInt<BITS> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const BITS: usize> ops::Rem for FixedUInt<BITS> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (_, r) = UInt::from(self).div_rem(&UInt::from(rhs));
+        Self::wrapping_from(&r)
+    }
+}
+
+impl<const BITS: usize> ops::RemAssign for FixedUInt<BITS> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
@@ -0,0 +1,601 @@
+use std::cmp::Ordering::*;
+use std::{cmp, fmt, iter, ops};
+
+use crate::{Boolean, UInt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    #[default]
+    NearestTiesToEven,
+    NearestTiesToAway,
+    TowardZero,
+    TowardPositive,
+    TowardNegative,
+}
+
+// IEEE 754 binary floating point number with an `EXP` bits biased exponent
+// and `MANT` bits of fraction, both stored little-endian
+#[derive(Clone, Copy)]
+pub struct Float<const EXP: usize, const MANT: usize> {
+    _sign: Boolean,
+    _exponent: [Boolean; EXP],
+    _mantissa: [Boolean; MANT],
+}
+
+pub type F16 = Float<5, 10>;
+pub type BF16 = Float<8, 7>;
+pub type F32 = Float<8, 23>;
+pub type F64 = Float<11, 52>;
+
+// a decoded finite value is `significand * 2^exponent`
+enum Class {
+    Nan,
+    Infinite,
+    Zero,
+    Finite(UInt, i64),
+}
+
+impl<const EXP: usize, const MANT: usize> Float<EXP, MANT> {
+    pub const ZERO: Self = Float {
+        _sign: Boolean::False,
+        _exponent: [Boolean::False; EXP],
+        _mantissa: [Boolean::False; MANT],
+    };
+    pub const NEG_ZERO: Self = Float {
+        _sign: Boolean::True,
+        ..Self::ZERO
+    };
+    pub const INFINITY: Self = Float {
+        _sign: Boolean::False,
+        _exponent: [Boolean::True; EXP],
+        _mantissa: [Boolean::False; MANT],
+    };
+    pub const NEG_INFINITY: Self = Float {
+        _sign: Boolean::True,
+        ..Self::INFINITY
+    };
+    // quiet NaN with the top fraction bit set
+    pub const NAN: Self = Float {
+        _sign: Boolean::False,
+        _exponent: [Boolean::True; EXP],
+        _mantissa: Self::quiet_mantissa([Boolean::False; MANT]),
+    };
+    pub const MAX: Self = Float {
+        _sign: Boolean::False,
+        _exponent: Self::max_finite_exponent(),
+        _mantissa: [Boolean::True; MANT],
+    };
+
+    const fn quiet_mantissa(mut mantissa: [Boolean; MANT]) -> [Boolean; MANT] {
+        mantissa[MANT - 1] = Boolean::True;
+        mantissa
+    }
+
+    const fn max_finite_exponent() -> [Boolean; EXP] {
+        let mut exponent = [Boolean::True; EXP];
+        exponent[0] = Boolean::False;
+        exponent
+    }
+
+    pub fn new(sign: Boolean, exponent: [Boolean; EXP], mantissa: [Boolean; MANT]) -> Self {
+        Float {
+            _sign: sign,
+            _exponent: exponent,
+            _mantissa: mantissa,
+        }
+    }
+
+    pub fn sign(&self) -> Boolean {
+        self._sign
+    }
+
+    pub fn exponent(&self) -> &[Boolean; EXP] {
+        &self._exponent
+    }
+
+    pub fn mantissa(&self) -> &[Boolean; MANT] {
+        &self._mantissa
+    }
+
+    // keeps the lowest 1 + EXP + MANT bits of `bits`,
+    // laid out as mantissa, exponent and sign from the bottom
+    pub fn from_bits(bits: &UInt) -> Self {
+        let mut iter = padded(bits);
+        let mantissa = field(&mut iter);
+        let exponent = field(&mut iter);
+        let sign = iter.next().unwrap_or(Boolean::False);
+        Float::new(sign, exponent, mantissa)
+    }
+
+    pub fn to_bits(&self) -> UInt {
+        let mut binary = self._mantissa.to_vec();
+        binary.extend_from_slice(&self._exponent);
+        binary.push(self._sign);
+        UInt::new(binary)
+    }
+
+    pub fn is_nan(&self) -> bool {
+        matches!(self.decode(), Class::Nan)
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        matches!(self.decode(), Class::Infinite)
+    }
+
+    pub fn is_finite(&self) -> bool {
+        !self.is_exponent_max()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        matches!(self.decode(), Class::Zero)
+    }
+
+    pub fn is_subnormal(&self) -> bool {
+        self.biased_exponent() == 0 && self._mantissa.contains(&Boolean::True)
+    }
+
+    pub fn is_sign_negative(&self) -> bool {
+        self._sign.into()
+    }
+
+    pub fn abs(self) -> Self {
+        Float {
+            _sign: Boolean::False,
+            ..self
+        }
+    }
+
+    pub fn add_with(self, rhs: Self, mode: RoundingMode) -> Self {
+        let (neg1, neg2) = (self.is_sign_negative(), rhs.is_sign_negative());
+        match (self.decode(), rhs.decode()) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::propagate_nan(self, rhs),
+            (Class::Infinite, Class::Infinite) if neg1 != neg2 => Self::NAN,
+            (Class::Infinite, _) => self,
+            (_, Class::Infinite) => rhs,
+            (Class::Zero, Class::Zero) => Self::zero(Self::exact_zero_sign(neg1, neg2, mode)),
+            (Class::Zero, _) => rhs,
+            (_, Class::Zero) => self,
+            (Class::Finite(s1, e1), Class::Finite(s2, e2)) => {
+                // align both significands to the smaller exponent, exactly
+                let e = cmp::min(e1, e2);
+                let a = s1 << (e1 - e) as usize;
+                let b = s2 << (e2 - e) as usize;
+                if neg1 == neg2 {
+                    return Self::round_pack(neg1, a + b, e, false, mode);
+                }
+                match a.cmp(&b) {
+                    Greater => Self::round_pack(neg1, a - b, e, false, mode),
+                    Less => Self::round_pack(neg2, b - a, e, false, mode),
+                    Equal => Self::zero(Self::exact_zero_sign(neg1, neg2, mode)),
+                }
+            }
+        }
+    }
+
+    pub fn sub_with(self, rhs: Self, mode: RoundingMode) -> Self {
+        self.add_with(-rhs, mode)
+    }
+
+    pub fn mul_with(self, rhs: Self, mode: RoundingMode) -> Self {
+        let negative = self.is_sign_negative() != rhs.is_sign_negative();
+        match (self.decode(), rhs.decode()) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::propagate_nan(self, rhs),
+            (Class::Infinite, Class::Zero) | (Class::Zero, Class::Infinite) => Self::NAN,
+            (Class::Infinite, _) | (_, Class::Infinite) => Self::infinity(negative),
+            (Class::Zero, _) | (_, Class::Zero) => Self::zero(negative),
+            (Class::Finite(s1, e1), Class::Finite(s2, e2)) => {
+                Self::round_pack(negative, s1 * s2, e1 + e2, false, mode)
+            }
+        }
+    }
+
+    pub fn div_with(self, rhs: Self, mode: RoundingMode) -> Self {
+        let negative = self.is_sign_negative() != rhs.is_sign_negative();
+        match (self.decode(), rhs.decode()) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::propagate_nan(self, rhs),
+            (Class::Infinite, Class::Infinite) | (Class::Zero, Class::Zero) => Self::NAN,
+            (Class::Infinite, _) | (_, Class::Zero) => Self::infinity(negative),
+            (Class::Zero, _) | (_, Class::Infinite) => Self::zero(negative),
+            (Class::Finite(s1, e1), Class::Finite(s2, e2)) => {
+                // at least MANT + 3 quotient bits, the rest goes to sticky
+                let k = (MANT + 3 + s2.max_bit_digit()).saturating_sub(s1.max_bit_digit());
+                let (q, r) = (s1 << k).div_rem(&s2);
+                let sticky = r.max_bit_digit() > 0;
+                Self::round_pack(negative, q, e1 - e2 - k as i64, sticky, mode)
+            }
+        }
+    }
+
+    pub fn sqrt_with(self, mode: RoundingMode) -> Self {
+        match self.decode() {
+            Class::Nan => Self::propagate_nan(self, self),
+            // sqrt(-0) == -0
+            Class::Zero => self,
+            _ if self.is_sign_negative() => Self::NAN,
+            Class::Infinite => self,
+            Class::Finite(s, e) => {
+                // at least MANT + 3 root bits, with an even exponent
+                let mut k = (2 * (MANT + 3)).saturating_sub(s.max_bit_digit());
+                if (e - k as i64) % 2 != 0 {
+                    k += 1;
+                }
+                let (root, rem) = (s << k).sqrt_rem();
+                let sticky = rem.max_bit_digit() > 0;
+                Self::round_pack(false, root, (e - k as i64) / 2, sticky, mode)
+            }
+        }
+    }
+
+    pub fn sqrt(self) -> Self {
+        self.sqrt_with(RoundingMode::NearestTiesToEven)
+    }
+
+    // rounds into another format
+    pub fn convert<const E2: usize, const M2: usize>(self, mode: RoundingMode) -> Float<E2, M2> {
+        let negative = self.is_sign_negative();
+        match self.decode() {
+            Class::Nan => Float::<E2, M2>::NAN,
+            Class::Infinite => Float::infinity(negative),
+            Class::Zero => Float::zero(negative),
+            Class::Finite(s, e) => Float::round_pack(negative, s, e, false, mode),
+        }
+    }
+
+    fn bias() -> i64 {
+        (1 << (EXP - 1)) - 1
+    }
+
+    fn biased_exponent(&self) -> i64 {
+        self._exponent
+            .iter()
+            .rev()
+            .fold(0, |acc, &b| acc << 1 | i64::from(bool::from(b)))
+    }
+
+    fn is_exponent_max(&self) -> bool {
+        self._exponent.iter().all(|&b| b == Boolean::True)
+    }
+
+    fn decode(&self) -> Class {
+        let fraction = UInt::new(self._mantissa.to_vec());
+        let is_zero = fraction.max_bit_digit() == 0;
+        if self.is_exponent_max() {
+            return if is_zero { Class::Infinite } else { Class::Nan };
+        }
+        match self.biased_exponent() {
+            0 if is_zero => Class::Zero,
+            // subnormal
+            0 => Class::Finite(fraction, 1 - Self::bias() - MANT as i64),
+            e => {
+                let significand = fraction + (UInt::from(1u8) << MANT);
+                Class::Finite(significand, e - Self::bias() - MANT as i64)
+            }
+        }
+    }
+
+    fn zero(negative: bool) -> Self {
+        if negative {
+            Self::NEG_ZERO
+        } else {
+            Self::ZERO
+        }
+    }
+
+    fn infinity(negative: bool) -> Self {
+        if negative {
+            Self::NEG_INFINITY
+        } else {
+            Self::INFINITY
+        }
+    }
+
+    // an exact zero sum is negative only for two negative operands,
+    // or when rounding toward negative
+    fn exact_zero_sign(neg1: bool, neg2: bool, mode: RoundingMode) -> bool {
+        if neg1 == neg2 {
+            neg1
+        } else {
+            mode == RoundingMode::TowardNegative
+        }
+    }
+
+    // the first NaN operand, quieted
+    fn propagate_nan(lhs: Self, rhs: Self) -> Self {
+        let nan = if lhs.is_nan() { lhs } else { rhs };
+        Float {
+            _mantissa: Self::quiet_mantissa(nan._mantissa),
+            ..nan
+        }
+    }
+
+    // rounds `(significand + sticky) * 2^exponent` into this format,
+    // where `sticky` stands for a nonzero amount below the last bit
+    fn round_pack(
+        negative: bool,
+        significand: UInt,
+        exponent: i64,
+        sticky: bool,
+        mode: RoundingMode,
+    ) -> Self {
+        let top = exponent + significand.max_bit_digit() as i64 - 1;
+        // the exponent of the last bit kept, subnormals keep fewer bits
+        let mut lsb = cmp::max(top, 1 - Self::bias()) - MANT as i64;
+        let shift = lsb - exponent;
+        let mut m = if shift > 0 {
+            round_shifted(&significand, shift as usize, sticky, negative, mode)
+        } else {
+            let significand = significand << shift.unsigned_abs() as usize;
+            round_shifted(&significand, 0, sticky, negative, mode)
+        };
+        if m.max_bit_digit() > MANT + 1 {
+            m >>= 1usize;
+            lsb += 1;
+        }
+        if m.max_bit_digit() == 0 {
+            return Self::zero(negative);
+        }
+        let biased = if m.max_bit_digit() == MANT + 1 {
+            lsb + MANT as i64 + Self::bias()
+        } else {
+            0
+        };
+        if biased >= (1 << EXP) - 1 {
+            let to_infinity = match mode {
+                RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => true,
+                RoundingMode::TowardZero => false,
+                RoundingMode::TowardPositive => !negative,
+                RoundingMode::TowardNegative => negative,
+            };
+            return if to_infinity {
+                Self::infinity(negative)
+            } else {
+                Float {
+                    _sign: Boolean::from(negative),
+                    ..Self::MAX
+                }
+            };
+        }
+        let exponent = field(&mut padded(&UInt::from(biased as u64)));
+        // the implicit leading bit falls off above MANT bits
+        let mantissa = field(&mut padded(&m));
+        Float::new(Boolean::from(negative), exponent, mantissa)
+    }
+}
+
+// `significand >> shift` rounded to an integer, where `sticky` stands
+// for a nonzero amount below the shifted out bits
+pub(crate) fn round_shifted(
+    significand: &UInt,
+    shift: usize,
+    sticky: bool,
+    negative: bool,
+    mode: RoundingMode,
+) -> UInt {
+    let (mut m, half, rest) = if shift > 0 {
+        let kept = significand >> shift;
+        let low = significand - &(&kept << shift);
+        let half_unit = UInt::from(1u8) << (shift - 1);
+        let half = low >= half_unit;
+        let below = if half { low - half_unit } else { low };
+        (kept, half, sticky || below.max_bit_digit() > 0)
+    } else {
+        (significand.clone(), false, sticky)
+    };
+    let inexact = half || rest;
+    let round_up = match mode {
+        RoundingMode::NearestTiesToEven => half && (rest || is_odd(&m)),
+        RoundingMode::NearestTiesToAway => half,
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => inexact && !negative,
+        RoundingMode::TowardNegative => inexact && negative,
+    };
+    if round_up {
+        m += 1u64;
+    }
+    m
+}
+
+// n / d rounded to an integer under `mode`, for a quotient of sign `negative`
+pub(crate) fn round_div(n: &UInt, d: &UInt, negative: bool, mode: RoundingMode) -> UInt {
+    let (q, r) = n.div_rem(d);
+    // one more quotient bit, the remainder below it goes to sticky
+    let twice = r << 1usize;
+    let half = &twice >= d;
+    let below = if half { twice - d } else { twice };
+    let q = (q << 1usize) + u64::from(half);
+    round_shifted(&q, 1, below.max_bit_digit() > 0, negative, mode)
+}
+
+fn is_odd(n: &UInt) -> bool {
+    n.limbs().first().is_some_and(|&l| l & 1 == 1)
+}
+
+// the bits of `n` followed by infinitely many zeros
+fn padded(n: &UInt) -> impl Iterator<Item = Boolean> + '_ {
+    n.bits().chain(iter::repeat(Boolean::False))
+}
+
+// the next N bits of an infinite bit stream
+fn field<const N: usize, I>(iter: &mut I) -> [Boolean; N]
+where
+    I: Iterator<Item = Boolean>,
+{
+    let mut binary = [Boolean::False; N];
+    for (b, bit) in binary.iter_mut().zip(iter) {
+        *b = bit;
+    }
+    binary
+}
+
+impl<const EXP: usize, const MANT: usize> Default for Float<EXP, MANT> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<f32> for F32 {
+    fn from(x: f32) -> Self {
+        Float::from_bits(&UInt::from(x.to_bits()))
+    }
+}
+
+impl From<F32> for f32 {
+    fn from(x: F32) -> Self {
+        let bits = u32::try_from(x.to_bits()).expect("32 bits fit in u32");
+        f32::from_bits(bits)
+    }
+}
+
+impl From<f64> for F64 {
+    fn from(x: f64) -> Self {
+        Float::from_bits(&UInt::from(x.to_bits()))
+    }
+}
+
+impl From<F64> for f64 {
+    fn from(x: F64) -> Self {
+        let bits = u64::try_from(x.to_bits()).expect("64 bits fit in u64");
+        f64::from_bits(bits)
+    }
+}
+
+impl<const EXP: usize, const MANT: usize> fmt::Debug for Float<EXP, MANT> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Float")
+            .field("sign", &self._sign)
+            .field("exponent", &self._exponent)
+            .field("mantissa", &self._mantissa)
+            .finish()
+    }
+}
+
+impl<const EXP: usize, const MANT: usize> fmt::Display for Float<EXP, MANT> {
+    // the exact value rounded to the requested precision, or else the
+    // shortest decimal which reads back as the same value, like f64 does
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let negative = self.is_sign_negative();
+        let (digits, k) = match self.decode() {
+            Class::Nan => return f.pad("NaN"),
+            Class::Infinite => return f.pad_integral(!negative, "", "inf"),
+            Class::Zero => (UInt::from(0u8), f.precision().unwrap_or(0) as i64),
+            Class::Finite(s, e) => {
+                // at a power of two the next value down is half as far
+                let narrow = s == UInt::from(1u8) << MANT && self.biased_exponent() > 1;
+                decimal_digits(&s, e, narrow, f.precision())
+            }
+        };
+        let s = if k <= 0 {
+            format!("{digits}{}", "0".repeat(k.unsigned_abs() as usize))
+        } else {
+            let k = k as usize;
+            let digits = format!("{digits:0>width$}", width = k + 1);
+            let (int, frac) = digits.split_at(digits.len() - k);
+            format!("{int}.{frac}")
+        };
+        f.pad_integral(!negative, "", &s)
+    }
+}
+
+// decimal digits of `s * 2^e` with the last `k` of them after the point:
+// rounded to `precision` places, or else the fewest digits which round
+// back to `s` under ties to even, where `narrow` halves the gap below
+fn decimal_digits(s: &UInt, e: i64, narrow: bool, precision: Option<usize>) -> (UInt, i64) {
+    let one = UInt::from(1u8);
+    let pow10 = |k: i64| UInt::from(10u8).pow(UInt::from(k.unsigned_abs()));
+    // s * 2^e * 10^k == s * p / den
+    let scaled = |k: i64| {
+        let p = (&one << e.max(0) as usize) * pow10(k.max(0));
+        let den = (&one << (-e).max(0) as usize) * pow10((-k).max(0));
+        (s * &p, den, p)
+    };
+    if let Some(precision) = precision {
+        let k = precision as i64;
+        let (num, den, _) = scaled(k);
+        return (
+            round_div(&num, &den, false, RoundingMode::NearestTiesToEven),
+            k,
+        );
+    }
+    // 10^(1 - k) > 2 * s * 2^e, so the first candidates have one digit
+    let top = s.max_bit_digit() as i64 + e;
+    let mut k = -((top + 2) as f64 * std::f64::consts::LOG10_2).ceil() as i64;
+    loop {
+        let (num, den, p) = scaled(k);
+        let q = &num / &den;
+        // distances and half gaps, all multiplied by 4 * den
+        let x = num << 2usize;
+        let above = ((&q + 1u64) * &den) << 2usize;
+        let below = (&q * &den) << 2usize;
+        let (d_below, d_above) = (&x - &below, &above - &x);
+        let gap_above = &p << 1usize;
+        let gap_below = if narrow { p } else { gap_above.clone() };
+        let fits = |d: &UInt, gap: &UInt| d < gap || (d == gap && !is_odd(s));
+        let nearer_below = d_below < d_above || (d_below == d_above && !is_odd(&q));
+        match (fits(&d_below, &gap_below), fits(&d_above, &gap_above)) {
+            (true, true) if nearer_below => return (q, k),
+            (true, false) => return (q, k),
+            (_, true) => return (q + 1u64, k),
+            (false, false) => k += 1,
+        }
+    }
+}
+
+impl<const EXP: usize, const MANT: usize> cmp::PartialEq for Float<EXP, MANT> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Equal)
+    }
+}
+
+impl<const EXP: usize, const MANT: usize> cmp::PartialOrd for Float<EXP, MANT> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        if self.is_zero() && other.is_zero() {
+            return Some(Equal);
+        }
+        // magnitudes order like their bits
+        let lhs = self.abs().to_bits();
+        let rhs = other.abs().to_bits();
+        Some(match (self.is_sign_negative(), other.is_sign_negative()) {
+            (false, true) => Greater,
+            (true, false) => Less,
+            (false, false) => lhs.cmp(&rhs),
+            (true, true) => rhs.cmp(&lhs),
+        })
+    }
+}
+
+impl<const EXP: usize, const MANT: usize> ops::Neg for Float<EXP, MANT> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Float {
+            _sign: !self._sign,
+            ..self
+        }
+    }
+}
+
+macro_rules! impl_arith {
+    ($($imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident, $with:ident;)*) => {$(
+        impl<const EXP: usize, const MANT: usize> ops::$imp for Float<EXP, MANT> {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$with(rhs, RoundingMode::NearestTiesToEven)
+            }
+        }
+
+        impl<const EXP: usize, const MANT: usize> ops::$imp_assign for Float<EXP, MANT> {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = self.$with(rhs, RoundingMode::NearestTiesToEven);
+            }
+        }
+    )*};
+}
+
+impl_arith! {
+    Add, add, AddAssign, add_assign, add_with;
+    Sub, sub, SubAssign, sub_assign, sub_with;
+    Mul, mul, MulAssign, mul_assign, mul_with;
+    Div, div, DivAssign, div_assign, div_with;
+}
@@ -0,0 +1,331 @@
+use std::cmp::Ordering::*;
+use std::{cmp, fmt, ops};
+
+use crate::{Boolean, UInt};
+
+#[derive(Eq, Clone)]
+pub struct Int {
+    _negative: bool,
+    _magnitude: UInt,
+}
+
+impl Int {
+    pub fn new(negative: bool, magnitude: UInt) -> Self {
+        // zero is never negative
+        let negative = negative && magnitude.max_bit_digit() > 0;
+        Int {
+            _negative: negative,
+            _magnitude: magnitude,
+        }
+    }
+
+    pub fn magnitude(&self) -> &UInt {
+        &self._magnitude
+    }
+
+    pub fn unsigned_abs(self) -> UInt {
+        self._magnitude
+    }
+
+    pub fn into_parts(self) -> (bool, UInt) {
+        (self._negative, self._magnitude)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self._negative
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self._negative && !self.is_zero()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self._magnitude.max_bit_digit() == 0
+    }
+
+    pub fn abs(&self) -> Self {
+        Int::new(false, self._magnitude.clone())
+    }
+
+    pub fn signum(&self) -> Self {
+        if self.is_zero() {
+            Int::new(false, UInt::new([]))
+        } else {
+            Int::new(self._negative, UInt::from(1u8))
+        }
+    }
+
+    // two's complement representation in `len` bits,
+    // every bit above `len` is equal to the sign
+    fn twos_complement(&self, len: usize) -> Vec<Boolean> {
+        if !self._negative {
+            let mut bin = self._magnitude.binary();
+            bin.resize(len, Boolean::False);
+            return bin;
+        }
+        let m = &self._magnitude - 1u64;
+        let mut bin = m.binary();
+        bin.resize(len, Boolean::False);
+        bin.into_iter().map(|b| !b).collect()
+    }
+
+    fn from_twos_complement(bin: Vec<Boolean>, negative: bool) -> Self {
+        if !negative {
+            return Int::new(false, UInt::new(bin));
+        }
+        let inverted: Vec<_> = bin.into_iter().map(|b| !b).collect();
+        Int::new(true, UInt::new(inverted) + 1u64)
+    }
+
+    fn bitwise<F>(&self, rhs: &Self, f: F) -> Self
+    where
+        F: Fn(Boolean, Boolean) -> Boolean,
+    {
+        let len = cmp::max(
+            self._magnitude.max_bit_digit(),
+            rhs._magnitude.max_bit_digit(),
+        );
+        let bin1 = self.twos_complement(len);
+        let bin2 = rhs.twos_complement(len);
+        let binary: Vec<_> = bin1
+            .into_iter()
+            .zip(bin2)
+            .map(|(b1, b2)| f(b1, b2))
+            .collect();
+        let sign = f(Boolean::from(self._negative), Boolean::from(rhs._negative));
+        Int::from_twos_complement(binary, sign.into())
+    }
+}
+
+impl From<UInt> for Int {
+    fn from(n: UInt) -> Self {
+        Int::new(false, n)
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Int {
+            fn from(n: $t) -> Self {
+                Int::new(n < 0, UInt::from(n.unsigned_abs()))
+            }
+        }
+    )*};
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Int {
+            fn from(n: $t) -> Self {
+                Int::new(false, UInt::from(n))
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl fmt::Debug for Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Int")
+            .field("negative", &self._negative)
+            .field("magnitude", &self._magnitude)
+            .finish()
+    }
+}
+
+impl fmt::Display for Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self._negative, "", &self._magnitude.to_str_radix(10))
+    }
+}
+
+impl cmp::PartialEq for Int {
+    fn eq(&self, other: &Self) -> bool {
+        self._negative == other._negative && self._magnitude == other._magnitude
+    }
+}
+
+impl cmp::Ord for Int {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self._negative, other._negative) {
+            (false, true) => Greater,
+            (true, false) => Less,
+            (false, false) => self._magnitude.cmp(&other._magnitude),
+            (true, true) => other._magnitude.cmp(&self._magnitude),
+        }
+    }
+}
+
+impl cmp::PartialOrd for Int {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ops::Neg for Int {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Int::new(!self._negative, self._magnitude)
+    }
+}
+
+impl ops::Not for Int {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        // !x == -x - 1
+        if self._negative {
+            Int::new(false, self._magnitude - 1u64)
+        } else {
+            Int::new(true, self._magnitude + 1u64)
+        }
+    }
+}
+
+impl ops::Add for Int {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        if self._negative == rhs._negative {
+            return Int::new(self._negative, self._magnitude + rhs._magnitude);
+        }
+        match self._magnitude.cmp(&rhs._magnitude) {
+            Less => Int::new(rhs._negative, rhs._magnitude - self._magnitude),
+            _ => Int::new(self._negative, self._magnitude - rhs._magnitude),
+        }
+    }
+}
+
+impl ops::AddAssign for Int {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl ops::Sub for Int {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl ops::SubAssign for Int {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl ops::Mul for Int {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let negative = self._negative != rhs._negative;
+        Int::new(negative, self._magnitude * rhs._magnitude)
+    }
+}
+
+impl ops::MulAssign for Int {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl ops::Div for Int {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        // rounds toward zero
+        let negative = self._negative != rhs._negative;
+        Int::new(negative, self._magnitude / rhs._magnitude)
+    }
+}
+
+impl ops::DivAssign for Int {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+impl ops::Rem for Int {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        // the sign of the result follows the dividend
+        Int::new(self._negative, self._magnitude % rhs._magnitude)
+    }
+}
+
+impl ops::RemAssign for Int {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.clone() % rhs;
+    }
+}
+
+impl ops::BitAnd for Int {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.bitwise(&rhs, |b1, b2| b1 & b2)
+    }
+}
+
+impl ops::BitAndAssign for Int {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.bitwise(&rhs, |b1, b2| b1 & b2);
+    }
+}
+
+impl ops::BitOr for Int {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.bitwise(&rhs, |b1, b2| b1 | b2)
+    }
+}
+
+impl ops::BitOrAssign for Int {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.bitwise(&rhs, |b1, b2| b1 | b2);
+    }
+}
+
+impl ops::BitXor for Int {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.bitwise(&rhs, |b1, b2| b1 ^ b2)
+    }
+}
+
+impl ops::BitXorAssign for Int {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.bitwise(&rhs, |b1, b2| b1 ^ b2);
+    }
+}
+
+impl ops::Shl<UInt> for Int {
+    type Output = Self;
+    fn shl(self, rhs: UInt) -> Self::Output {
+        Int::new(self._negative, self._magnitude << rhs)
+    }
+}
+
+impl ops::ShlAssign<UInt> for Int {
+    fn shl_assign(&mut self, rhs: UInt) {
+        *self = self.clone() << rhs;
+    }
+}
+
+impl ops::Shr<UInt> for Int {
+    type Output = Self;
+    fn shr(self, rhs: UInt) -> Self::Output {
+        if !self._negative {
+            return Int::new(false, self._magnitude >> rhs);
+        }
+        // arithmetic shift rounds toward negative infinity:
+        // -m >> k == -(((m - 1) >> k) + 1)
+        let m = ((self._magnitude - 1u64) >> rhs) + 1u64;
+        Int::new(true, m)
+    }
+}
+
+impl ops::ShrAssign<UInt> for Int {
+    fn shr_assign(&mut self, rhs: UInt) {
+        *self = self.clone() >> rhs;
+    }
+}
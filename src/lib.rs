@@ -1,5 +1,33 @@
+mod big_float;
+pub use big_float::{BigFloat, BigFloatErrorKind, ParseBigFloatError};
+
 mod boolean;
 pub use boolean::Boolean;
 
 mod uint;
-pub use uint::UInt;
+pub use uint::{BarrettCtx, MontgomeryCtx, MulConfig, ParseUIntError, TryFromUIntError, UInt};
+
+mod int;
+pub use int::Int;
+
+mod fixed;
+pub use fixed::Fixed;
+
+mod fixed_uint;
+pub use fixed_uint::{FixedUInt, U128, U16, U256, U32, U64, U8};
+
+mod float;
+pub use float::{Float, RoundingMode, BF16, F16, F32, F64};
+
+mod mod_uint;
+pub use mod_uint::ModUInt;
+
+mod pow;
+pub use pow::Pow;
+
+mod rational;
+pub use rational::{ParseRationalError, Rational, RationalErrorKind};
+
+pub mod num_theory;
+
+mod xorshift;
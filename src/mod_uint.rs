@@ -0,0 +1,163 @@
+use std::rc::Rc;
+use std::{fmt, ops};
+
+use crate::num_theory::mod_inverse;
+use crate::UInt;
+
+// a residue modulo a shared modulus, always kept reduced
+#[derive(Eq, Clone)]
+pub struct ModUInt {
+    _value: UInt,
+    _modulus: Rc<UInt>,
+}
+
+impl ModUInt {
+    pub fn new(value: UInt, modulus: Rc<UInt>) -> Self {
+        if modulus.max_bit_digit() == 0 {
+            panic!("attempt to use a modulus of zero");
+        }
+        ModUInt {
+            _value: value % &*modulus,
+            _modulus: modulus,
+        }
+    }
+
+    pub fn value(&self) -> &UInt {
+        &self._value
+    }
+
+    pub fn into_value(self) -> UInt {
+        self._value
+    }
+
+    pub fn modulus(&self) -> &Rc<UInt> {
+        &self._modulus
+    }
+
+    pub fn pow(&self, exp: &UInt) -> Self {
+        let value = self._value.pow_mod(exp, &self._modulus);
+        self.with_value(value)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        mod_inverse(&self._value, &self._modulus).map(|v| self.with_value(v))
+    }
+
+    // `value` must already be reduced
+    fn with_value(&self, value: UInt) -> Self {
+        ModUInt {
+            _value: value,
+            _modulus: self._modulus.clone(),
+        }
+    }
+
+    fn check_modulus(&self, rhs: &Self) {
+        if !Rc::ptr_eq(&self._modulus, &rhs._modulus) && self._modulus != rhs._modulus {
+            panic!("moduli of the operands differ");
+        }
+    }
+}
+
+impl fmt::Debug for ModUInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModUInt")
+            .field("value", &self._value)
+            .field("modulus", &self._modulus)
+            .finish()
+    }
+}
+
+impl fmt::Display for ModUInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self._value, f)
+    }
+}
+
+impl PartialEq for ModUInt {
+    fn eq(&self, other: &Self) -> bool {
+        self._value == other._value && self._modulus == other._modulus
+    }
+}
+
+impl ops::Neg for ModUInt {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        if self._value.max_bit_digit() == 0 {
+            return self;
+        }
+        let value = &*self._modulus - &self._value;
+        self.with_value(value)
+    }
+}
+
+impl ops::Add for ModUInt {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        let mut value = &self._value + &rhs._value;
+        if value >= *self._modulus {
+            value -= &*self._modulus;
+        }
+        self.with_value(value)
+    }
+}
+
+impl ops::AddAssign for ModUInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl ops::Sub for ModUInt {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        let value = if self._value >= rhs._value {
+            &self._value - &rhs._value
+        } else {
+            &self._value + &(&*self._modulus - &rhs._value)
+        };
+        self.with_value(value)
+    }
+}
+
+impl ops::SubAssign for ModUInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl ops::Mul for ModUInt {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        let value = &self._value * &rhs._value % &*self._modulus;
+        self.with_value(value)
+    }
+}
+
+impl ops::MulAssign for ModUInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl ops::Div for ModUInt {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        match mod_inverse(&rhs._value, &rhs._modulus) {
+            Some(inv) => {
+                let value = &self._value * &inv % &*self._modulus;
+                self.with_value(value)
+            }
+            None => panic!("attempt to divide by a non-invertible residue"),
+        }
+    }
+}
+
+impl ops::DivAssign for ModUInt {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
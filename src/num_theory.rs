@@ -0,0 +1,131 @@
+use std::{error, fmt, mem};
+
+use crate::{Int, UInt};
+
+mod factor;
+pub use factor::{factor, factor_with_limit, Factorization};
+
+// binary gcd (Stein's algorithm)
+pub fn gcd(a: &UInt, b: &UInt) -> UInt {
+    if a.max_bit_digit() == 0 {
+        return b.clone();
+    }
+    if b.max_bit_digit() == 0 {
+        return a.clone();
+    }
+    let za = a.trailing_zeros().unwrap_or(0);
+    let zb = b.trailing_zeros().unwrap_or(0);
+    let mut a = a >> za;
+    let mut b = b >> zb;
+    // both odd from here on
+    loop {
+        if a > b {
+            mem::swap(&mut a, &mut b);
+        }
+        b -= &a;
+        if b.max_bit_digit() == 0 {
+            return a << za.min(zb);
+        }
+        let z = b.trailing_zeros().unwrap_or(0);
+        b >>= z;
+    }
+}
+
+// returns (g, x, y) with a * x + b * y == g == gcd(a, b)
+pub fn extended_gcd(a: &UInt, b: &UInt) -> (UInt, Int, Int) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (Int::from(1u8), Int::from(0u8));
+    let (mut t0, mut t1) = (Int::from(0u8), Int::from(1u8));
+    while r1.max_bit_digit() > 0 {
+        let (q, r) = r0.div_rem(&r1);
+        let q = Int::from(q);
+        (r0, r1) = (r1, r);
+        let s = s0 - q.clone() * s1.clone();
+        (s0, s1) = (s1, s);
+        let t = t0 - q * t1.clone();
+        (t0, t1) = (t1, t);
+    }
+    (r0, s0, t0)
+}
+
+pub fn lcm(a: &UInt, b: &UInt) -> UInt {
+    if a.max_bit_digit() == 0 || b.max_bit_digit() == 0 {
+        return UInt::new([]);
+    }
+    a / &gcd(a, b) * b
+}
+
+// the inverse of `a` modulo `m`, if it exists
+pub fn mod_inverse(a: &UInt, m: &UInt) -> Option<UInt> {
+    if m.max_bit_digit() == 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(&(a % m), m);
+    if g != UInt::from(1u8) {
+        return None;
+    }
+    let (negative, x) = x.into_parts();
+    let x = x % m;
+    if negative && x.max_bit_digit() > 0 {
+        Some(m - &x)
+    } else {
+        Some(x)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    ZeroModulus { index: usize },
+    // the congruence at `index` contradicts the ones before it
+    Inconsistent { index: usize },
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::ZeroModulus { index } => write!(f, "modulus of congruence {index} is zero"),
+            CrtError::Inconsistent { index } => write!(
+                f,
+                "congruence {index} is inconsistent with the preceding congruences"
+            ),
+        }
+    }
+}
+
+impl error::Error for CrtError {}
+
+// solves x == residue (mod modulus) for every pair, returning
+// (x, lcm of the moduli) with x reduced modulo the lcm
+pub fn crt(congruences: &[(UInt, UInt)]) -> Result<(UInt, UInt), CrtError> {
+    let mut r = UInt::new([]);
+    let mut m = UInt::from(1u8);
+    for (index, (a, n)) in congruences.iter().enumerate() {
+        if n.max_bit_digit() == 0 {
+            return Err(CrtError::ZeroModulus { index });
+        }
+        let a = a % n;
+        // m * p == g (mod n)
+        let (g, p, _) = extended_gcd(&m, n);
+        let (negative, diff) = if a >= r {
+            (false, &a - &r)
+        } else {
+            (true, &r - &a)
+        };
+        let (q, rem) = diff.div_rem(&g);
+        if rem.max_bit_digit() > 0 {
+            return Err(CrtError::Inconsistent { index });
+        }
+        // k == (a - r) / g * p (mod n / g) makes r + m * k a solution
+        let n_g = n / &g;
+        let (p_negative, p) = p.into_parts();
+        let k = q * p % &n_g;
+        let k = if negative != p_negative && k.max_bit_digit() > 0 {
+            &n_g - &k
+        } else {
+            k
+        };
+        r += &m * &k;
+        m *= n_g;
+    }
+    Ok((r, m))
+}
@@ -0,0 +1,213 @@
+use crate::UInt;
+
+use super::gcd;
+
+const TRIAL_BOUND: u64 = 1000;
+const PM1_BOUND: u64 = 2000;
+// number of products gathered per gcd in Brent's rho
+const RHO_BATCH: u64 = 128;
+
+// a factorization that may be incomplete when the work limit runs out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization {
+    factors: Vec<(UInt, u32)>,
+    remaining: Vec<UInt>,
+}
+
+impl Factorization {
+    // prime factors with their exponents, in increasing order
+    pub fn factors(&self) -> &[(UInt, u32)] {
+        &self.factors
+    }
+
+    // composite cofactors which could not be split
+    pub fn remaining(&self) -> &[UInt] {
+        &self.remaining
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.remaining.is_empty()
+    }
+}
+
+// the complete factorization; there is no bound on the running time,
+// which for a product of two large primes (2^128 + 1 for instance) is
+// minutes or far more, so use `factor_with_limit` to cap the work
+pub fn factor(n: &UInt) -> Vec<(UInt, u32)> {
+    let res = factor_with_limit(n, u64::MAX);
+    // 2^64 multiplications are never spent in practice
+    assert!(res.is_complete(), "work limit exhausted while factoring");
+    res.factors
+}
+
+// `work_limit` bounds the number of modular multiplications spent in
+// Pollard's p-1 and rho methods
+pub fn factor_with_limit(n: &UInt, work_limit: u64) -> Factorization {
+    if is_zero(n) {
+        panic!("attempt to factor zero");
+    }
+    let mut primes = Vec::new();
+    let mut remaining = Vec::new();
+    let mut budget = work_limit;
+
+    let m = trial_division(n, &mut primes);
+    let mut stack = vec![m];
+    while let Some(m) = stack.pop() {
+        if m == UInt::from(1u8) {
+            continue;
+        }
+        if m.is_probable_prime(8) {
+            primes.push(m);
+            continue;
+        }
+        if let Some((root, k)) = perfect_power(&m) {
+            stack.extend((0..k).map(|_| root.clone()));
+            continue;
+        }
+        let divisor = pollard_pm1(&m, &mut budget).or_else(|| brent_rho(&m, &mut budget));
+        match divisor {
+            Some(d) => {
+                stack.push(&m / &d);
+                stack.push(d);
+            }
+            None => remaining.push(m),
+        }
+    }
+
+    primes.sort();
+    let mut factors: Vec<(UInt, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    remaining.sort();
+    Factorization { factors, remaining }
+}
+
+fn is_zero(n: &UInt) -> bool {
+    n.max_bit_digit() == 0
+}
+
+fn mul_mod(a: &UInt, b: &UInt, n: &UInt) -> UInt {
+    a * b % n
+}
+
+fn abs_diff(a: &UInt, b: &UInt) -> UInt {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+// strips factors up to TRIAL_BOUND into `primes`, returns the cofactor
+fn trial_division(n: &UInt, primes: &mut Vec<UInt>) -> UInt {
+    let mut m = n.clone();
+    let mut d = 2u64;
+    while d < TRIAL_BOUND {
+        let d_uint = UInt::from(d);
+        if &d_uint * &d_uint > m {
+            break;
+        }
+        loop {
+            let (q, r) = m.div_rem(&d_uint);
+            if !is_zero(&r) {
+                break;
+            }
+            primes.push(d_uint.clone());
+            m = q;
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    if m > UInt::from(1u8) && m < UInt::from(TRIAL_BOUND * TRIAL_BOUND) {
+        // no factor below the square root is left
+        primes.push(m);
+        return UInt::from(1u8);
+    }
+    m
+}
+
+// (r, k) with r^k == n for the largest such k > 1
+fn perfect_power(n: &UInt) -> Option<(UInt, u32)> {
+    // every prime factor exceeds TRIAL_BOUND > 2^9
+    let max_k = n.max_bit_digit() / 9;
+    (2..=max_k as u32).rev().find_map(|k| {
+        let r = n.nth_root(k);
+        (r.pow(k) == *n).then_some((r, k))
+    })
+}
+
+// stage one of Pollard's p-1 method with smoothness bound PM1_BOUND
+fn pollard_pm1(n: &UInt, budget: &mut u64) -> Option<UInt> {
+    let one = UInt::from(1u8);
+    let mut a = UInt::from(2u8);
+    for q in 2..=PM1_BOUND {
+        if !UInt::from(q).is_probable_prime(0) {
+            continue;
+        }
+        // the largest power of q within the bound
+        let mut qe = q;
+        while qe * q <= PM1_BOUND {
+            qe *= q;
+        }
+        let cost = 2 * u64::from(qe.ilog2() + 1);
+        if *budget < cost {
+            return None;
+        }
+        *budget -= cost;
+        a = a.pow_mod(&UInt::from(qe), n);
+    }
+    let g = gcd(&(a - one), n);
+    (g > UInt::from(1u8) && g < *n).then_some(g)
+}
+
+// Pollard's rho with Brent's cycle detection and batched gcds
+fn brent_rho(n: &UInt, budget: &mut u64) -> Option<UInt> {
+    let one = UInt::from(1u8);
+    for c in 1u64.. {
+        let c = UInt::from(c);
+        let f = |x: &UInt| (mul_mod(x, x, n) + &c) % n;
+        let mut y = UInt::from(2u8);
+        let mut x = y.clone();
+        let mut ys = y.clone();
+        let mut q = one.clone();
+        let mut g = one.clone();
+        let mut r = 1u64;
+        while g == one {
+            x = y.clone();
+            for _ in 0..r {
+                *budget = budget.checked_sub(1)?;
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g == one {
+                ys = y.clone();
+                for _ in 0..RHO_BATCH.min(r - k) {
+                    *budget = budget.checked_sub(2)?;
+                    y = f(&y);
+                    q = mul_mod(&q, &abs_diff(&x, &y), n);
+                }
+                g = gcd(&q, n);
+                k += RHO_BATCH;
+            }
+            r *= 2;
+        }
+        if g == *n {
+            // the batch overshot, step through it one by one
+            loop {
+                *budget = budget.checked_sub(1)?;
+                ys = f(&ys);
+                g = gcd(&abs_diff(&x, &ys), n);
+                if g > one {
+                    break;
+                }
+            }
+        }
+        if g != *n {
+            return Some(g);
+        }
+    }
+    None
+}
@@ -0,0 +1,29 @@
+use crate::UInt;
+
+// implemented for references only, so that `x.pow(e)` with the trait
+// in scope still resolves to the borrowing inherent `UInt::pow`
+pub trait Pow<Rhs> {
+    type Output;
+    fn pow(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Pow<u32> for &UInt {
+    type Output = UInt;
+    fn pow(self, rhs: u32) -> Self::Output {
+        self.pow_uint(&UInt::from(rhs))
+    }
+}
+
+impl Pow<&UInt> for &UInt {
+    type Output = UInt;
+    fn pow(self, rhs: &UInt) -> Self::Output {
+        self.pow_uint(rhs)
+    }
+}
+
+impl Pow<UInt> for &UInt {
+    type Output = UInt;
+    fn pow(self, rhs: UInt) -> Self::Output {
+        self.pow_uint(&rhs)
+    }
+}
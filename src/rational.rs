@@ -0,0 +1,366 @@
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+use std::{cmp, error, fmt, ops};
+
+use crate::num_theory::gcd;
+use crate::{Int, UInt};
+
+// kept in lowest terms with a positive denominator,
+// zero is never negative
+#[derive(Eq, Clone)]
+pub struct Rational {
+    _negative: bool,
+    _numer: UInt,
+    _denom: UInt,
+}
+
+impl Rational {
+    pub fn new(negative: bool, numer: UInt, denom: UInt) -> Self {
+        if denom.max_bit_digit() == 0 {
+            panic!("attempt to create a rational with a zero denominator");
+        }
+        let g = gcd(&numer, &denom);
+        let numer = numer / &g;
+        let denom = denom / &g;
+        Rational {
+            _negative: negative && numer.max_bit_digit() > 0,
+            _numer: numer,
+            _denom: denom,
+        }
+    }
+
+    pub fn numer(&self) -> &UInt {
+        &self._numer
+    }
+
+    pub fn denom(&self) -> &UInt {
+        &self._denom
+    }
+
+    pub fn into_parts(self) -> (bool, UInt, UInt) {
+        (self._negative, self._numer, self._denom)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self._negative
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self._numer.max_bit_digit() == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self._denom == UInt::from(1u8)
+    }
+
+    pub fn abs(&self) -> Self {
+        Rational {
+            _negative: false,
+            ..self.clone()
+        }
+    }
+
+    pub fn recip(&self) -> Self {
+        if self.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        Rational {
+            _negative: self._negative,
+            _numer: self._denom.clone(),
+            _denom: self._numer.clone(),
+        }
+    }
+
+    // the exact value of a finite float
+    pub fn from_f64(x: f64) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let bits = x.to_bits();
+        let negative = bits >> 63 == 1;
+        let exp = (bits >> 52 & 0x7ff) as i32;
+        let frac = bits & ((1 << 52) - 1);
+        // x == mant * 2^exp
+        let (mant, exp) = match exp {
+            0 => (frac, -1074),
+            _ => (frac | 1 << 52, exp - 1075),
+        };
+        let one = UInt::from(1u8);
+        let mant = UInt::from(mant);
+        Some(if exp >= 0 {
+            Rational::new(negative, mant << exp as usize, one)
+        } else {
+            Rational::new(negative, mant, one << exp.unsigned_abs() as usize)
+        })
+    }
+
+    // the nearest float, ties to even
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let (n, d) = (&self._numer, &self._denom);
+        // q == floor(n * 2^s / d) has at least 65 bits
+        let s = 65 - (n.max_bit_digit() as i64 - d.max_bit_digit() as i64);
+        let (q, r) = if s >= 0 {
+            (n << s as usize).div_rem(d)
+        } else {
+            let d = d << s.unsigned_abs() as usize;
+            n.div_rem(&d)
+        };
+        let sticky = r.max_bit_digit() > 0;
+        let bits = q.max_bit_digit() as i64;
+        // the exponent of the leading bit
+        let e = bits - 1 - s;
+        if e > 1023 {
+            return if self._negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+        }
+        // 53 bits of precision, fewer for subnormals
+        let precision = if e >= -1022 { 53 } else { 53 - (-1022 - e) };
+        let drop = (bits - precision) as usize;
+        let kept = &q >> drop;
+        let low = &q - &(&kept << drop);
+        let mut mant = u64::try_from(kept).unwrap_or(0);
+        // compare the dropped part with one half
+        let round_up = match (low << 1usize).cmp(&(UInt::from(1u8) << drop)) {
+            Greater => true,
+            Equal => sticky || mant & 1 == 1,
+            Less => false,
+        };
+        if round_up {
+            mant += 1;
+        }
+        let res = mant as f64 * pow2(drop as i64 - s);
+        if self._negative {
+            -res
+        } else {
+            res
+        }
+    }
+}
+
+// 2^k for -1074 <= k, may overflow to infinity
+fn pow2(k: i64) -> f64 {
+    if k > 1023 {
+        f64::INFINITY
+    } else if k >= -1022 {
+        f64::from_bits(((k + 1023) as u64) << 52)
+    } else {
+        f64::from_bits(1 << (k + 1074))
+    }
+}
+
+impl From<UInt> for Rational {
+    fn from(n: UInt) -> Self {
+        Rational::new(false, n, UInt::from(1u8))
+    }
+}
+
+impl From<Int> for Rational {
+    fn from(n: Int) -> Self {
+        let (negative, n) = n.into_parts();
+        Rational::new(negative, n, UInt::from(1u8))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Rational {
+            fn from(n: $t) -> Self {
+                Rational::from(Int::from(n))
+            }
+        }
+    )*};
+}
+
+impl_from_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rational")
+            .field("negative", &self._negative)
+            .field("numer", &self._numer)
+            .field("denom", &self._denom)
+            .finish()
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = format!("{}/{}", self._numer, self._denom);
+        f.pad_integral(!self._negative, "", &digits)
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+    // accepts "p/q" and decimals like "-0.125", with an optional sign
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseRationalError::new(RationalErrorKind::Empty));
+        }
+        let invalid = || ParseRationalError::new(RationalErrorKind::InvalidDigit);
+        let (negative, body) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let is_digits = |t: &str| !t.is_empty() && t.bytes().all(|b| b.is_ascii_digit());
+        let parse = |t: &str| t.parse::<UInt>().map_err(|_| invalid());
+        if let Some((p, q)) = body.split_once('/') {
+            if !is_digits(p) || !is_digits(q) {
+                return Err(invalid());
+            }
+            let denom = parse(q)?;
+            if denom.max_bit_digit() == 0 {
+                return Err(ParseRationalError::new(RationalErrorKind::ZeroDenominator));
+            }
+            return Ok(Rational::new(negative, parse(p)?, denom));
+        }
+        let (int, frac) = body.split_once('.').unwrap_or((body, ""));
+        let valid = (int.is_empty() || is_digits(int)) && (frac.is_empty() || is_digits(frac));
+        if !valid || int.len() + frac.len() == 0 {
+            return Err(invalid());
+        }
+        let numer = parse(&format!("{int}{frac}"))?;
+        let denom = UInt::from(10u8).pow(frac.len() as u32);
+        Ok(Rational::new(negative, numer, denom))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RationalErrorKind {
+    Empty,
+    InvalidDigit,
+    ZeroDenominator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError {
+    kind: RationalErrorKind,
+}
+
+impl ParseRationalError {
+    fn new(kind: RationalErrorKind) -> Self {
+        ParseRationalError { kind }
+    }
+
+    pub fn kind(&self) -> &RationalErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            RationalErrorKind::Empty => "cannot parse rational from empty string",
+            RationalErrorKind::InvalidDigit => "invalid digit found in string",
+            RationalErrorKind::ZeroDenominator => "denominator is zero",
+        };
+        f.write_str(message)
+    }
+}
+
+impl error::Error for ParseRationalError {}
+
+impl cmp::PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self._negative == other._negative
+            && self._numer == other._numer
+            && self._denom == other._denom
+    }
+}
+
+impl cmp::Ord for Rational {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        // a / b <=> c / d is a * d <=> c * b for positive b and d
+        let lhs = &self._numer * &other._denom;
+        let rhs = &other._numer * &self._denom;
+        match (self._negative, other._negative) {
+            (false, true) => Greater,
+            (true, false) => Less,
+            (false, false) => lhs.cmp(&rhs),
+            (true, true) => rhs.cmp(&lhs),
+        }
+    }
+}
+
+impl cmp::PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ops::Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Rational {
+            _negative: !self._negative && !self.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl ops::Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let lhs = Int::new(self._negative, self._numer * &rhs._denom);
+        let rhs_numer = Int::new(rhs._negative, rhs._numer * &self._denom);
+        let (negative, numer) = (lhs + rhs_numer).into_parts();
+        Rational::new(negative, numer, self._denom * rhs._denom)
+    }
+}
+
+impl ops::AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl ops::Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl ops::SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl ops::Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let negative = self._negative != rhs._negative;
+        Rational::new(negative, self._numer * rhs._numer, self._denom * rhs._denom)
+    }
+}
+
+impl ops::MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl ops::Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let negative = self._negative != rhs._negative;
+        Rational::new(negative, self._numer * rhs._denom, self._denom * rhs._numer)
+    }
+}
+
+impl ops::DivAssign for Rational {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
@@ -1,11 +1,32 @@
 use std::cmp::Ordering::*;
-use std::{cmp, fmt, ops};
+use std::num::IntErrorKind;
+use std::str::FromStr;
+use std::{cmp, error, fmt, ops};
 
 use crate::Boolean;
 
+mod barrett;
+mod bits;
+mod checked;
+mod montgomery;
+mod mul;
+mod ntt;
+mod pow;
+mod prime;
+mod root;
+
+pub use barrett::BarrettCtx;
+pub use montgomery::MontgomeryCtx;
+pub use mul::MulConfig;
+
+const LIMB_BITS: usize = u64::BITS as usize;
+
+// bits are packed little-endian into `_limbs`,
+// and every bit at or above `_len` is kept False
 #[derive(Eq, Clone)]
 pub struct UInt {
-    _binary: Vec<Boolean>,
+    _limbs: Vec<u64>,
+    _len: usize,
 }
 
 impl UInt {
@@ -13,391 +34,665 @@ impl UInt {
     where
         T: Into<Vec<Boolean>>,
     {
+        let binary: Vec<Boolean> = binary.into();
+        let mut limbs = vec![0; binary.len().div_ceil(LIMB_BITS)];
+        for (i, &b) in binary.iter().enumerate() {
+            limbs[i / LIMB_BITS] |= u64::from(bool::from(b)) << (i % LIMB_BITS);
+        }
         UInt {
-            _binary: binary.into(),
+            _limbs: limbs,
+            _len: binary.len(),
         }
     }
 
-    pub fn binary(&self) -> &Vec<Boolean> {
-        &self._binary
+    pub fn from_limbs<T>(limbs: T) -> Self
+    where
+        T: Into<Vec<u64>>,
+    {
+        let limbs: Vec<u64> = limbs.into();
+        let len = limbs.len() * LIMB_BITS;
+        UInt {
+            _limbs: limbs,
+            _len: len,
+        }
+    }
+
+    pub fn limbs(&self) -> &[u64] {
+        &self._limbs
+    }
+
+    pub fn binary(&self) -> Vec<Boolean> {
+        self.bits().collect()
+    }
+
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = Boolean> + ExactSizeIterator + '_ {
+        (0..self._len).map(|i| Boolean::from(self.get_bit(i)))
     }
 
     pub fn bit_len(&self) -> usize {
-        self._binary.len()
+        self._len
     }
 
     pub fn max_bit_digit(&self) -> usize {
-        let ln = self.bit_len();
-        for i in (0..ln).rev() {
-            let b = self._binary[i];
-            if b.into() {
-                return i + 1;
-            }
+        let ln = self.limb_len();
+        if ln == 0 {
+            return 0;
         }
-        0
+        ln * LIMB_BITS - self._limbs[ln - 1].leading_zeros() as usize
     }
 
     pub fn trim(&self) -> Self {
-        if self._binary.is_empty() {
-            return UInt::new([]);
+        let mut res = self.clone();
+        res.trim_mut();
+        res
+    }
+
+    pub fn trim_mut(&mut self) {
+        self._limbs.truncate(self.limb_len());
+        self._len = self.max_bit_digit();
+    }
+
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, but got {radix}"
+        );
+        let (chunk, width) = radix_chunk(radix);
+        let mut digits = vec![];
+        let mut n = self.trim();
+        while !n.is_zero() {
+            let (q, mut r) = n.div_rem_digit(chunk);
+            for _ in 0..width {
+                digits.push(char::from_digit((r % radix as u64) as u32, radix).unwrap());
+                r /= radix as u64;
+            }
+            n = q;
         }
-        let last_true_index = self.max_bit_digit();
-        if last_true_index == 0 {
-            // self._binary is [False, False, ...]
-            return UInt::new([]);
+        while digits.last() == Some(&'0') {
+            digits.pop();
         }
-        UInt::new(&self._binary[0..last_true_index])
+        if digits.is_empty() {
+            return String::from("0");
+        }
+        digits.iter().rev().collect()
     }
 
-    pub fn trim_mut(&mut self) {
-        let ln = self.max_bit_digit();
-        self._binary.resize(ln, Boolean::False);
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseUIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, but got {radix}"
+        );
+        if src.is_empty() {
+            return Err(ParseUIntError::new(IntErrorKind::Empty));
+        }
+        let digits = src.strip_prefix('+').unwrap_or(src);
+        if digits.is_empty() {
+            return Err(ParseUIntError::new(IntErrorKind::InvalidDigit));
+        }
+        let digits = digits
+            .chars()
+            .map(|c| c.to_digit(radix))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ParseUIntError::new(IntErrorKind::InvalidDigit))?;
+        let (_, width) = radix_chunk(radix);
+        let mut res = UInt::new([]);
+        for group in digits.chunks(width) {
+            let mut m = 1;
+            let mut a = 0;
+            for &d in group {
+                m *= radix as u64;
+                a = a * radix as u64 + d as u64;
+            }
+            res.mul_add_digit(m, a);
+        }
+        Ok(res)
     }
-}
 
-impl From<u64> for UInt {
-    fn from(n: u64) -> Self {
-        let len = u64::BITS - n.leading_zeros();
-        let it = (0..len).map(|i| Boolean::from(n >> i & 1 == 1));
-        Self {
-            _binary: it.collect(),
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
         }
+        // restoring long division, one quotient bit per step
+        let len = self.max_bit_digit();
+        let mut quot = vec![0; len.div_ceil(LIMB_BITS)];
+        let mut rem = UInt::new([]);
+        for i in (0..len).rev() {
+            rem.shl1_push(self.get_bit(i));
+            if rem >= *rhs {
+                rem = rem.sub_ref(rhs);
+                quot[i / LIMB_BITS] |= 1 << (i % LIMB_BITS);
+            }
+        }
+        (UInt::from_raw_limbs(quot), rem)
+    }
+
+    fn div_ref(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).0
     }
-}
 
-impl From<UInt> for u64 {
-    fn from(n: UInt) -> Self {
-        let mut res = 0;
-        for (i, &b) in n.binary().iter().enumerate() {
-            res |= u64::from(<Boolean as std::convert::Into<bool>>::into(b)) << i;
+    fn rem_ref(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    // number of limbs up to the most significant non-zero one
+    fn limb_len(&self) -> usize {
+        self._limbs
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .map_or(0, |i| i + 1)
+    }
+
+    fn limb(&self, i: usize) -> u64 {
+        *self._limbs.get(i).unwrap_or(&0)
+    }
+
+    fn get_bit(&self, i: usize) -> bool {
+        self.limb(i / LIMB_BITS) >> (i % LIMB_BITS) & 1 == 1
+    }
+
+    fn is_zero(&self) -> bool {
+        self._limbs.iter().all(|&limb| limb == 0)
+    }
+
+    // the lowest `width` bits, keeping `width` as the bit length
+    fn truncated(&self, width: usize) -> Self {
+        let mut limbs: Vec<_> = (0..width.div_ceil(LIMB_BITS))
+            .map(|i| self.limb(i))
+            .collect();
+        let r = width % LIMB_BITS;
+        if let (Some(last), true) = (limbs.last_mut(), r > 0) {
+            *last &= (1 << r) - 1;
+        }
+        UInt {
+            _limbs: limbs,
+            _len: width,
         }
+    }
+
+    fn from_raw_limbs(limbs: Vec<u64>) -> Self {
+        let mut res = UInt::from_limbs(limbs);
+        res.trim_mut();
         res
     }
-}
 
-impl fmt::Debug for UInt {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("UInt")
-            .field("binary", &self._binary)
-            .finish()
+    // (self / d, self % d) for a single limb divisor
+    fn div_rem_digit(&self, d: u64) -> (Self, u64) {
+        let d = d as u128;
+        let mut rem = 0;
+        let mut quot = vec![0; self._limbs.len()];
+        for i in (0..self._limbs.len()).rev() {
+            let cur = rem << LIMB_BITS | self._limbs[i] as u128;
+            quot[i] = (cur / d) as u64;
+            rem = cur % d;
+        }
+        (UInt::from_raw_limbs(quot), rem as u64)
     }
-}
 
-impl cmp::PartialEq for UInt {
-    fn eq(&self, other: &Self) -> bool {
-        let len = self.max_bit_digit();
-        if len != other.max_bit_digit() {
-            return false;
+    // self = self * m + a for single limb m and a
+    fn mul_add_digit(&mut self, m: u64, a: u64) {
+        let mut carry = a as u128;
+        for limb in self._limbs.iter_mut() {
+            let v = *limb as u128 * m as u128 + carry;
+            *limb = v as u64;
+            carry = v >> LIMB_BITS;
         }
-        self.binary()[..len]
-            .iter()
-            .zip(other.binary()[..len].iter())
-            .all(|(&b1, &b2)| b1 == b2)
+        self._limbs.push(carry as u64);
+        self.trim_mut();
     }
-}
 
-impl cmp::Ord for UInt {
-    fn cmp(&self, other: &Self) -> cmp::Ordering {
-        let len = self.max_bit_digit();
-        match len.cmp(&other.max_bit_digit()) {
-            Equal => {
-                let bin1 = self.binary();
-                let bin2 = other.binary();
-                (0..len)
-                    .rev()
-                    .map(|i| (bin1[i] as u8, bin2[i] as u8))
-                    .map(|(b1, b2)| b1.cmp(&b2))
-                    .fold(Equal, |acc, m| match (acc, m) {
-                        (Equal, x) => x,
-                        (x, _) => x,
-                    })
+    fn bitwise<F>(&self, rhs: &Self, len: usize, f: F) -> Self
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let limbs = (0..len).map(|i| f(self.limb(i), rhs.limb(i))).collect();
+        UInt::from_raw_limbs(limbs)
+    }
+
+    fn shl_bits(&self, rhs: usize) -> Self {
+        if self.is_zero() {
+            return UInt::new([]);
+        }
+        if self.max_bit_digit().checked_add(rhs).is_none() {
+            panic!("attempt to shift left with overflow");
+        }
+        let (q, r) = (rhs / LIMB_BITS, rhs % LIMB_BITS);
+        let mut limbs = vec![0; q];
+        if r == 0 {
+            limbs.extend_from_slice(&self._limbs);
+        } else {
+            let mut carry = 0;
+            for &limb in self._limbs.iter() {
+                limbs.push(limb << r | carry);
+                carry = limb >> (LIMB_BITS - r);
             }
-            x => x,
+            limbs.push(carry);
         }
+        UInt::from_raw_limbs(limbs)
     }
-}
 
-impl cmp::PartialOrd for UInt {
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        Some(self.cmp(other))
+    // self = self << 1 | bit, in place
+    fn shl1_push(&mut self, bit: bool) {
+        let mut carry = u64::from(bit);
+        for limb in self._limbs.iter_mut() {
+            let next = *limb >> (LIMB_BITS - 1);
+            *limb = *limb << 1 | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            self._limbs.push(carry);
+        }
+        self._len = self.max_bit_digit();
     }
-}
 
-impl ops::BitAnd for UInt {
-    type Output = Self;
-    fn bitand(self, rhs: Self) -> Self::Output {
-        let len = cmp::min(self.max_bit_digit(), rhs.max_bit_digit());
-        let bin1 = self.binary();
-        let bin2 = rhs.binary();
-        let binary: Vec<_> = (0..len)
-            .map(|i| {
-                (
-                    bin1.get(i).unwrap_or(&Boolean::False),
-                    bin2.get(i).unwrap_or(&Boolean::False),
-                )
-            })
-            .map(|(&b1, &b2)| b1 & b2)
-            .collect();
-        Self::Output { _binary: binary }
+    fn shr_bits(&self, rhs: usize) -> Self {
+        let (q, r) = (rhs / LIMB_BITS, rhs % LIMB_BITS);
+        if q >= self._limbs.len() {
+            return UInt::new([]);
+        }
+        let src = &self._limbs[q..];
+        let limbs = if r == 0 {
+            src.to_vec()
+        } else {
+            (0..src.len())
+                .map(|i| src[i] >> r | src.get(i + 1).map_or(0, |&hi| hi << (LIMB_BITS - r)))
+                .collect()
+        };
+        UInt::from_raw_limbs(limbs)
+    }
+
+    fn add_ref(&self, rhs: &Self) -> Self {
+        let len = cmp::max(self._limbs.len(), rhs._limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = false;
+        for i in 0..len {
+            let (s1, c1) = self.limb(i).overflowing_add(rhs.limb(i));
+            let (s2, c2) = s1.overflowing_add(u64::from(carry));
+            limbs.push(s2);
+            carry = c1 || c2;
+        }
+        limbs.push(u64::from(carry));
+        UInt::from_raw_limbs(limbs)
     }
-}
 
-impl ops::BitAndAssign for UInt {
-    fn bitand_assign(&mut self, rhs: Self) {
-        let len = cmp::min(self.max_bit_digit(), rhs.max_bit_digit());
-        self._binary.resize(len, Boolean::False);
-        let bin1 = &mut self._binary;
-        let bin2 = rhs.binary();
-        let it = bin1.iter_mut().zip(bin2.iter()).take(len);
-        for (b1, b2) in it {
-            *b1 &= *b2;
+    // ripple-borrow subtraction of the lowest `len` limbs
+    fn sub_limbs(&self, rhs: &Self, len: usize) -> (Vec<u64>, bool) {
+        let mut limbs = Vec::with_capacity(len);
+        let mut borrow = false;
+        for i in 0..len {
+            let (d1, b1) = self.limb(i).overflowing_sub(rhs.limb(i));
+            let (d2, b2) = d1.overflowing_sub(u64::from(borrow));
+            limbs.push(d2);
+            borrow = b1 || b2;
+        }
+        (limbs, borrow)
+    }
+
+    fn sub_ref(&self, rhs: &Self) -> Self {
+        let len = cmp::max(self.limb_len(), rhs.limb_len());
+        let (limbs, borrow) = self.sub_limbs(rhs, len);
+        if borrow {
+            panic!("attempt to subtract with overflow");
         }
+        UInt::from_raw_limbs(limbs)
+    }
+
+    fn bitand_ref(&self, rhs: &Self) -> Self {
+        let len = cmp::min(self.limb_len(), rhs.limb_len());
+        self.bitwise(rhs, len, |l1, l2| l1 & l2)
+    }
+
+    fn bitor_ref(&self, rhs: &Self) -> Self {
+        let len = cmp::max(self.limb_len(), rhs.limb_len());
+        self.bitwise(rhs, len, |l1, l2| l1 | l2)
+    }
+
+    fn bitxor_ref(&self, rhs: &Self) -> Self {
+        let len = cmp::max(self.limb_len(), rhs.limb_len());
+        self.bitwise(rhs, len, |l1, l2| l1 ^ l2)
     }
 }
 
-impl ops::BitOr for UInt {
-    type Output = Self;
-    fn bitor(self, rhs: Self) -> Self::Output {
-        let len = cmp::max(self.max_bit_digit(), rhs.max_bit_digit());
-        let bin1 = self.binary();
-        let bin2 = rhs.binary();
-        let binary: Vec<_> = (0..len)
-            .map(|i| {
-                (
-                    bin1.get(i).unwrap_or(&Boolean::False),
-                    bin2.get(i).unwrap_or(&Boolean::False),
-                )
-            })
-            .map(|(&b1, &b2)| b1 | b2)
-            .collect();
-        Self::Output { _binary: binary }
+// the largest power of `radix` fitting in a limb, and its exponent
+fn radix_chunk(radix: u32) -> (u64, usize) {
+    let mut chunk = radix as u64;
+    let mut width = 1;
+    while let Some(c) = chunk.checked_mul(radix as u64) {
+        chunk = c;
+        width += 1;
     }
+    (chunk, width)
 }
 
-impl ops::BitOrAssign for UInt {
-    fn bitor_assign(&mut self, rhs: Self) {
-        let len = cmp::max(self.max_bit_digit(), rhs.max_bit_digit());
-        self._binary.resize(len, Boolean::False);
-        let bin1 = &mut self._binary;
-        let bin2 = rhs.binary();
-        let it = bin1.iter_mut().enumerate().take(len);
-        for (i, b) in it {
-            *b |= *bin2.get(i).unwrap_or(&Boolean::False);
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for UInt {
+            fn from(n: $t) -> Self {
+                let n = n as u128;
+                UInt::from_raw_limbs(vec![n as u64, (n >> LIMB_BITS) as u64])
+            }
         }
-    }
+
+        impl TryFrom<UInt> for $t {
+            type Error = TryFromUIntError;
+            fn try_from(n: UInt) -> Result<Self, Self::Error> {
+                if n.max_bit_digit() > <$t>::BITS as usize {
+                    return Err(TryFromUIntError(()));
+                }
+                let res = n.limb(0) as u128 | (n.limb(1) as u128) << LIMB_BITS;
+                Ok(res as $t)
+            }
+        }
+    )*};
 }
 
-impl ops::BitXor for UInt {
-    type Output = Self;
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        let len = cmp::max(self.max_bit_digit(), rhs.max_bit_digit());
-        let bin1 = self.binary();
-        let bin2 = rhs.binary();
-        let mut binary: Vec<_> = (0..len)
-            .map(|i| {
-                (
-                    bin1.get(i).unwrap_or(&Boolean::False),
-                    bin2.get(i).unwrap_or(&Boolean::False),
-                )
-            })
-            .map(|(&b1, &b2)| b1 ^ b2)
-            .collect();
-        for i in (0..len).rev() {
-            if binary[i].into() {
-                #[allow(clippy::suspicious_arithmetic_impl)]
-                binary.resize(i + 1, Boolean::False);
-                break;
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_try_from_signed {
+    ($($t:ty),*) => {$(
+        impl TryFrom<$t> for UInt {
+            type Error = TryFromUIntError;
+            fn try_from(n: $t) -> Result<Self, Self::Error> {
+                if n < 0 {
+                    return Err(TryFromUIntError(()));
+                }
+                Ok(UInt::from(n.unsigned_abs()))
+            }
+        }
+
+        impl TryFrom<UInt> for $t {
+            type Error = TryFromUIntError;
+            fn try_from(n: UInt) -> Result<Self, Self::Error> {
+                if n.max_bit_digit() >= <$t>::BITS as usize {
+                    return Err(TryFromUIntError(()));
+                }
+                let res = n.limb(0) as u128 | (n.limb(1) as u128) << LIMB_BITS;
+                Ok(res as $t)
             }
         }
-        Self::Output { _binary: binary }
+    )*};
+}
+
+impl_try_from_signed!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromUIntError(pub(crate) ());
+
+impl fmt::Display for TryFromUIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
     }
 }
 
-impl ops::BitXorAssign for UInt {
-    fn bitxor_assign(&mut self, rhs: Self) {
-        let len = cmp::max(self.max_bit_digit(), rhs.max_bit_digit());
-        let bin1 = &mut self._binary;
-        let bin2 = rhs.binary();
-        bin1.resize(len, Boolean::False);
-        let it = bin1.iter_mut().enumerate().take(len);
-        for (i, b) in it {
-            *b ^= *bin2.get(i).unwrap_or(&Boolean::False);
-        }
-        self.trim_mut();
+impl error::Error for TryFromUIntError {}
+
+impl fmt::Debug for UInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UInt")
+            .field("binary", &self.binary())
+            .finish()
+    }
+}
+
+impl fmt::Display for UInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &self.to_str_radix(10))
     }
 }
 
-impl ops::Shl for UInt {
-    type Output = Self;
-    fn shl(self, rhs: Self) -> Self::Output {
-        let rhs: u64 = rhs.into();
-        let mut bin = vec![Boolean::False; rhs as usize];
-        bin.extend_from_slice(self.binary());
-        Self::Output { _binary: bin }
+impl fmt::Binary for UInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0b", &self.to_str_radix(2))
     }
 }
 
-impl ops::ShlAssign for UInt {
-    fn shl_assign(&mut self, rhs: Self) {
-        let rhs: u64 = rhs.into();
-        let mut bin = vec![Boolean::False; rhs as usize];
-        bin.extend(self._binary.iter());
-        self._binary = bin;
+impl fmt::Octal for UInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0o", &self.to_str_radix(8))
     }
 }
 
-impl ops::Shr for UInt {
-    type Output = Self;
-    fn shr(self, rhs: Self) -> Self::Output {
-        let rhs = cmp::min(u64::from(rhs) as usize, self.bit_len());
-        Self::new(&self.binary()[rhs..])
+impl fmt::LowerHex for UInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_str_radix(16))
     }
 }
 
-impl ops::ShrAssign for UInt {
-    fn shr_assign(&mut self, rhs: Self) {
-        let rhs = cmp::min(u64::from(rhs) as usize, self.bit_len());
-        self._binary = Vec::from(&self._binary[rhs..]);
+impl fmt::UpperHex for UInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_str_radix(16).to_uppercase())
     }
 }
 
-impl ops::Add for UInt {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        let bin1 = self.binary();
-        let bin2 = rhs.binary();
-        let dig1 = |i: usize| *bin1.get(i).unwrap_or(&Boolean::False);
-        let dig2 = |i: usize| *bin2.get(i).unwrap_or(&Boolean::False);
-        let mut sums = vec![];
-        sums.push(dig1(0) ^ dig2(0));
-        let mut carry = dig1(0) & dig2(0);
-        let len = cmp::max(self.max_bit_digit(), rhs.max_bit_digit());
-        for i in 0..len {
-            let (a, b, c) = (dig1(i + 1), dig2(i + 1), carry);
-            sums.push(a ^ b ^ c);
-            carry = (a & b) | (b & c) | (c & a);
+impl FromStr for UInt {
+    type Err = ParseUIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        let (digits, radix) = match digits.get(..2) {
+            Some("0b") => (&digits[2..], 2),
+            Some("0o") => (&digits[2..], 8),
+            Some("0x") => (&digits[2..], 16),
+            _ => return Self::from_str_radix(s, 10),
+        };
+        if digits.is_empty() || digits.starts_with('+') {
+            return Err(ParseUIntError::new(IntErrorKind::InvalidDigit));
         }
-        let mut res = Self { _binary: sums };
-        res.trim_mut();
-        res
+        Self::from_str_radix(digits, radix)
     }
 }
 
-impl ops::AddAssign for UInt {
-    fn add_assign(&mut self, rhs: Self) {
-        let len = cmp::max(self.max_bit_digit(), rhs.max_bit_digit());
-        let bin1 = &mut self._binary;
-        let bin2 = rhs.binary();
-        let dig2 = |i: usize| *bin2.get(i).unwrap_or(&Boolean::False);
-        bin1.resize(len + 1, Boolean::False);
-        let mut carry = bin1[0] & dig2(0);
-        bin1[0] ^= dig2(0);
-        let it = bin1
-            .iter_mut()
-            .enumerate()
-            .skip(1)
-            .map(|(i, bin)| (bin, dig2(i)));
-        for (bin, b) in it {
-            let a = *bin;
-            *bin = a ^ b ^ carry;
-            carry = (a & b) | (b & carry) | (carry & a);
-        }
-        self.trim_mut();
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUIntError {
+    kind: IntErrorKind,
+}
+
+impl ParseUIntError {
+    fn new(kind: IntErrorKind) -> Self {
+        ParseUIntError { kind }
+    }
+
+    pub fn kind(&self) -> &IntErrorKind {
+        &self.kind
     }
 }
 
-impl ops::Mul for UInt {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        let mut res = UInt::new([]);
-        let bin2 = rhs.binary();
-        let it = bin2.iter().enumerate();
-        for (i, b) in it {
-            if !bool::from(*b) {
-                continue;
-            }
-            res += self.clone() << UInt::from(i as u64);
-        }
-        res
+impl fmt::Display for ParseUIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            IntErrorKind::Empty => "cannot parse integer from empty string",
+            _ => "invalid digit found in string",
+        };
+        f.write_str(message)
     }
 }
 
-impl ops::MulAssign for UInt {
-    fn mul_assign(&mut self, rhs: Self) {
-        self._binary = (self.clone() * rhs)._binary;
+impl error::Error for ParseUIntError {}
+
+impl cmp::PartialEq for UInt {
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.limb_len();
+        len == other.limb_len() && self._limbs[..len] == other._limbs[..len]
     }
 }
 
-impl ops::Sub for UInt {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        let n1 = UInt::from(1);
-        let mut le = UInt::from(0);
-        let mut gt = self.clone() + n1.clone();
-        if rhs.clone() + le.clone() > self {
-            panic!("attempt to subtract with overflow");
-        }
-        // binary search
-        while gt > le.clone() + n1.clone() {
-            let mid = (gt.clone() + le.clone()) >> n1.clone();
-            if mid.clone() + rhs.clone() > self {
-                gt = mid;
-            } else {
-                le = mid;
-            }
+impl cmp::Ord for UInt {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let len = self.limb_len();
+        match len.cmp(&other.limb_len()) {
+            Equal => self._limbs[..len]
+                .iter()
+                .rev()
+                .cmp(other._limbs[..len].iter().rev()),
+            x => x,
         }
-        le
     }
 }
 
-impl ops::SubAssign for UInt {
-    fn sub_assign(&mut self, rhs: Self) {
-        self._binary = (self.clone() - rhs)._binary;
+impl cmp::PartialOrd for UInt {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl ops::Div for UInt {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        let n1 = UInt::from(1);
-        let mut le = UInt::from(0);
-        let mut gt = self.clone() + n1.clone();
-        if rhs == UInt::from(0) {
-            panic!("attempt to divide by zero");
+macro_rules! impl_binop {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $core:path) => {
+        impl ops::$imp<&UInt> for &UInt {
+            type Output = UInt;
+            fn $method(self, rhs: &UInt) -> Self::Output {
+                $core(self, rhs)
+            }
+        }
+
+        impl ops::$imp<UInt> for &UInt {
+            type Output = UInt;
+            fn $method(self, rhs: UInt) -> Self::Output {
+                $core(self, &rhs)
+            }
+        }
+
+        impl ops::$imp<&UInt> for UInt {
+            type Output = UInt;
+            fn $method(self, rhs: &UInt) -> Self::Output {
+                $core(&self, rhs)
+            }
         }
-        // binary search
-        while gt > le.clone() + n1.clone() {
-            let mid = (gt.clone() + le.clone()) >> n1.clone();
-            if mid.clone() * rhs.clone() > self {
-                gt = mid;
-            } else {
-                le = mid;
+
+        impl ops::$imp for UInt {
+            type Output = UInt;
+            fn $method(self, rhs: UInt) -> Self::Output {
+                $core(&self, &rhs)
+            }
+        }
+
+        impl ops::$assign_imp<&UInt> for UInt {
+            fn $assign_method(&mut self, rhs: &UInt) {
+                *self = $core(self, rhs);
+            }
+        }
+
+        impl ops::$assign_imp for UInt {
+            fn $assign_method(&mut self, rhs: UInt) {
+                *self = $core(self, &rhs);
+            }
+        }
+
+        impl_binop!(@primitive $imp, $method, $assign_imp, $assign_method, $core, u64, usize);
+    };
+    (@primitive $imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $core:path, $($t:ty),*) => {$(
+        impl ops::$imp<$t> for &UInt {
+            type Output = UInt;
+            fn $method(self, rhs: $t) -> Self::Output {
+                $core(self, &UInt::from(rhs))
+            }
+        }
+
+        impl ops::$imp<$t> for UInt {
+            type Output = UInt;
+            fn $method(self, rhs: $t) -> Self::Output {
+                $core(&self, &UInt::from(rhs))
             }
         }
-        le
+
+        impl ops::$imp<&UInt> for $t {
+            type Output = UInt;
+            fn $method(self, rhs: &UInt) -> Self::Output {
+                $core(&UInt::from(self), rhs)
+            }
+        }
+
+        impl ops::$imp<UInt> for $t {
+            type Output = UInt;
+            fn $method(self, rhs: UInt) -> Self::Output {
+                $core(&UInt::from(self), &rhs)
+            }
+        }
+
+        impl ops::$assign_imp<$t> for UInt {
+            fn $assign_method(&mut self, rhs: $t) {
+                *self = $core(self, &UInt::from(rhs));
+            }
+        }
+    )*};
+}
+
+impl_binop!(
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    UInt::bitand_ref
+);
+impl_binop!(BitOr, bitor, BitOrAssign, bitor_assign, UInt::bitor_ref);
+impl_binop!(
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    UInt::bitxor_ref
+);
+impl_binop!(Add, add, AddAssign, add_assign, UInt::add_ref);
+impl_binop!(Sub, sub, SubAssign, sub_assign, UInt::sub_ref);
+impl_binop!(Mul, mul, MulAssign, mul_assign, UInt::mul_ref);
+impl_binop!(Div, div, DivAssign, div_assign, UInt::div_ref);
+impl_binop!(Rem, rem, RemAssign, rem_assign, UInt::rem_ref);
+
+macro_rules! impl_shift {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $core:path) => {
+        impl_shift!(@rhs $imp, $method, $assign_imp, $assign_method, $core, UInt, &UInt, u64, usize);
+    };
+    (@rhs $imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $core:path, $($t:ty),*) => {$(
+        impl ops::$imp<$t> for &UInt {
+            type Output = UInt;
+            fn $method(self, rhs: $t) -> Self::Output {
+                $core(self, rhs.shift_amount())
+            }
+        }
+
+        impl ops::$imp<$t> for UInt {
+            type Output = UInt;
+            fn $method(self, rhs: $t) -> Self::Output {
+                $core(&self, rhs.shift_amount())
+            }
+        }
+
+        impl ops::$assign_imp<$t> for UInt {
+            fn $assign_method(&mut self, rhs: $t) {
+                *self = $core(self, rhs.shift_amount());
+            }
+        }
+    )*};
+}
+
+impl_shift!(Shl, shl, ShlAssign, shl_assign, UInt::shl_bits);
+impl_shift!(Shr, shr, ShrAssign, shr_assign, UInt::shr_bits);
+
+// shift amounts saturate at usize::MAX, which is out of reach for any UInt
+trait ShiftAmount {
+    fn shift_amount(&self) -> usize;
+}
+
+impl ShiftAmount for UInt {
+    fn shift_amount(&self) -> usize {
+        if self.max_bit_digit() > usize::BITS as usize {
+            return usize::MAX;
+        }
+        self.limb(0) as usize
     }
 }
 
-impl ops::DivAssign for UInt {
-    fn div_assign(&mut self, rhs: Self) {
-        self._binary = (self.clone() / rhs)._binary;
+impl ShiftAmount for &UInt {
+    fn shift_amount(&self) -> usize {
+        (*self).shift_amount()
     }
 }
 
-impl ops::Rem for UInt {
-    type Output = Self;
-    fn rem(self, rhs: Self) -> Self::Output {
-        // d, m = divmod(self, rhs)
-        // self = rhs * d + m
-        // m = self - rhs * d
-        let d = self.clone() / rhs.clone();
-        let r = d * rhs;
-        self - r
+impl ShiftAmount for u64 {
+    fn shift_amount(&self) -> usize {
+        usize::try_from(*self).unwrap_or(usize::MAX)
     }
 }
 
-impl ops::RemAssign for UInt {
-    fn rem_assign(&mut self, rhs: Self) {
-        self._binary = (self.clone() % rhs)._binary;
+impl ShiftAmount for usize {
+    fn shift_amount(&self) -> usize {
+        *self
     }
 }
@@ -0,0 +1,62 @@
+use super::pow::sliding_window_pow;
+use super::UInt;
+
+// arithmetic modulo a fixed modulus, reducing by a precomputed
+// reciprocal instead of division
+#[derive(Debug, Clone)]
+pub struct BarrettCtx {
+    modulus: UInt,
+    // bit length of modulus
+    k: usize,
+    // floor(2^(2k) / modulus)
+    mu: UInt,
+}
+
+impl BarrettCtx {
+    pub fn new(modulus: &UInt) -> Self {
+        if modulus.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let k = modulus.max_bit_digit();
+        let mu = UInt::from(1u8).shl_bits(2 * k).div_ref(modulus);
+        BarrettCtx {
+            modulus: modulus.clone(),
+            k,
+            mu,
+        }
+    }
+
+    pub fn modulus(&self) -> &UInt {
+        &self.modulus
+    }
+
+    pub fn reduce(&self, a: &UInt) -> UInt {
+        if a.max_bit_digit() > 2 * self.k {
+            return a.rem_ref(&self.modulus);
+        }
+        // q underestimates a / modulus by at most 2
+        let q = a
+            .shr_bits(self.k.saturating_sub(1))
+            .mul_ref(&self.mu)
+            .shr_bits(self.k + 1);
+        let mut r = a.sub_ref(&q.mul_ref(&self.modulus));
+        while r >= self.modulus {
+            r = r.sub_ref(&self.modulus);
+        }
+        r
+    }
+
+    // the operands below must be reduced
+    pub fn mul(&self, a: &UInt, b: &UInt) -> UInt {
+        self.reduce(&a.mul_ref(b))
+    }
+
+    pub fn square(&self, a: &UInt) -> UInt {
+        self.mul(a, a)
+    }
+
+    pub fn pow(&self, a: &UInt, exp: &UInt) -> UInt {
+        let one = self.reduce(&UInt::from(1u8));
+        sliding_window_pow(a, exp, one, |x, y| self.mul(x, y))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::Boolean;
+
+use super::{UInt, LIMB_BITS};
+
+// single bit access; bits past `bit_len` read as False,
+// and writing one grows the storage to include it
+impl UInt {
+    pub fn bit(&self, i: usize) -> Boolean {
+        Boolean::from(self.get_bit(i))
+    }
+
+    pub fn set_bit(&mut self, i: usize, value: Boolean) {
+        let limb = i / LIMB_BITS;
+        if limb >= self._limbs.len() {
+            self._limbs.resize(limb + 1, 0);
+        }
+        let mask = 1 << (i % LIMB_BITS);
+        if bool::from(value) {
+            self._limbs[limb] |= mask;
+        } else {
+            self._limbs[limb] &= !mask;
+        }
+        self._len = self._len.max(i + 1);
+    }
+
+    pub fn clear_bit(&mut self, i: usize) {
+        self.set_bit(i, Boolean::False);
+    }
+
+    pub fn toggle_bit(&mut self, i: usize) {
+        self.set_bit(i, !self.bit(i));
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self._limbs.iter().map(|l| l.count_ones() as usize).sum()
+    }
+
+    // zeros within `bit_len`
+    pub fn count_zeros(&self) -> usize {
+        self._len - self.count_ones()
+    }
+
+    // zeros above the highest set bit in a register of `width` bits
+    pub fn leading_zeros(&self, width: usize) -> usize {
+        let used = self.max_bit_digit();
+        assert!(
+            used <= width,
+            "value needs {used} bits, which exceeds the width {width}"
+        );
+        width - used
+    }
+
+    // None for zero, which has no lowest set bit
+    pub fn trailing_zeros(&self) -> Option<usize> {
+        let i = self._limbs.iter().position(|&l| l != 0)?;
+        Some(i * LIMB_BITS + self._limbs[i].trailing_zeros() as usize)
+    }
+
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    // the smallest power of two not below `self`, 1 for zero
+    pub fn next_power_of_two(&self) -> Self {
+        if self.is_zero() {
+            return UInt::from(1u8);
+        }
+        let mut res = UInt::new([]);
+        let high = self.max_bit_digit() - 1;
+        res.set_bit(high + usize::from(!self.is_power_of_two()), Boolean::True);
+        res
+    }
+
+    pub fn ilog2(&self) -> usize {
+        if self.is_zero() {
+            panic!("argument of integer logarithm must be positive");
+        }
+        self.max_bit_digit() - 1
+    }
+}
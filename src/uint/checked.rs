@@ -0,0 +1,114 @@
+use std::cmp;
+
+use super::{UInt, LIMB_BITS};
+
+// `overflowing_*` and `wrapping_*` work modulo 2^width for an explicit
+// `width`, and their results keep `width` as the bit length
+impl UInt {
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.add_ref(rhs))
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let len = cmp::max(self.limb_len(), rhs.limb_len());
+        let (limbs, borrow) = self.sub_limbs(rhs, len);
+        if borrow {
+            return None;
+        }
+        Some(UInt::from_raw_limbs(limbs))
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self.mul_ref(rhs))
+    }
+
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self.div_ref(rhs))
+    }
+
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self.rem_ref(rhs))
+    }
+
+    pub fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self.div_rem(rhs))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(UInt::new([]));
+        }
+        None
+    }
+
+    // None only when the bit length of the result overflows usize,
+    // any other shift is carried out however large it gets
+    pub fn checked_shl(&self, rhs: usize) -> Option<Self> {
+        if !self.is_zero() {
+            self.max_bit_digit().checked_add(rhs)?;
+        }
+        Some(self.shl_bits(rhs))
+    }
+
+    pub fn checked_shr(&self, rhs: usize) -> Option<Self> {
+        Some(self.shr_bits(rhs))
+    }
+
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        self.add_ref(rhs)
+    }
+
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(UInt::new([]))
+    }
+
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.mul_ref(rhs)
+    }
+
+    pub fn wrapping_add(&self, rhs: &Self, width: usize) -> Self {
+        self.overflowing_add(rhs, width).0
+    }
+
+    pub fn wrapping_sub(&self, rhs: &Self, width: usize) -> Self {
+        self.overflowing_sub(rhs, width).0
+    }
+
+    pub fn wrapping_mul(&self, rhs: &Self, width: usize) -> Self {
+        self.overflowing_mul(rhs, width).0
+    }
+
+    // the flag tells whether the exact sum does not fit in `width` bits
+    pub fn overflowing_add(&self, rhs: &Self, width: usize) -> (Self, bool) {
+        let sum = self.add_ref(rhs);
+        (sum.truncated(width), sum.max_bit_digit() > width)
+    }
+
+    // the flag is the borrow (self < rhs), like `u64::overflowing_sub`;
+    // a difference wider than `width` is truncated without raising it
+    pub fn overflowing_sub(&self, rhs: &Self, width: usize) -> (Self, bool) {
+        if self >= rhs {
+            return (self.sub_ref(rhs).truncated(width), false);
+        }
+        // -d mod 2^width == (2^width - d mod 2^width) mod 2^width
+        let d = rhs.sub_ref(self).truncated(width);
+        let len = width.div_ceil(LIMB_BITS);
+        let (limbs, _) = UInt::new([]).sub_limbs(&d, len);
+        (UInt::from_limbs(limbs).truncated(width), true)
+    }
+
+    // the flag tells whether the exact product does not fit in `width` bits
+    pub fn overflowing_mul(&self, rhs: &Self, width: usize) -> (Self, bool) {
+        let prod = self.mul_ref(rhs);
+        (prod.truncated(width), prod.max_bit_digit() > width)
+    }
+}
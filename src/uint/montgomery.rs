@@ -0,0 +1,115 @@
+use std::borrow::Cow;
+
+use super::pow::sliding_window_pow;
+use super::{UInt, LIMB_BITS};
+
+// arithmetic modulo an odd modulus on numbers in Montgomery form,
+// a * R mod modulus with R = 2^(64 * limbs of modulus)
+#[derive(Debug, Clone)]
+pub struct MontgomeryCtx {
+    modulus: UInt,
+    // -modulus^-1 mod 2^64
+    inv: u64,
+    // R^2 mod modulus
+    r2: UInt,
+    // R mod modulus
+    one: UInt,
+}
+
+impl MontgomeryCtx {
+    pub fn new(modulus: &UInt) -> Self {
+        if !modulus.get_bit(0) {
+            panic!("Montgomery modulus must be odd");
+        }
+        let modulus = modulus.clone();
+        let k = modulus.limb_len();
+        // Newton's iteration doubles the correct low bits each step
+        let m0 = modulus.limb(0);
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+        }
+        let r = UInt::from(1u8).shl_bits(k * LIMB_BITS);
+        let one = r.rem_ref(&modulus);
+        let r2 = (&one * &one).rem_ref(&modulus);
+        MontgomeryCtx {
+            modulus,
+            inv: inv.wrapping_neg(),
+            r2,
+            one,
+        }
+    }
+
+    pub fn modulus(&self) -> &UInt {
+        &self.modulus
+    }
+
+    pub fn to_montgomery(&self, a: &UInt) -> UInt {
+        self.mul(a, &self.r2)
+    }
+
+    pub fn from_montgomery(&self, a: &UInt) -> UInt {
+        let a = self.reduced(a);
+        self.redc(&a._limbs[..a.limb_len()])
+    }
+
+    // the operands and results below are in Montgomery form
+    pub fn one(&self) -> UInt {
+        self.one.clone()
+    }
+
+    pub fn mul(&self, a: &UInt, b: &UInt) -> UInt {
+        let t = self.reduced(a).mul_ref(&self.reduced(b));
+        self.redc(&t._limbs[..t.limb_len()])
+    }
+
+    pub fn square(&self, a: &UInt) -> UInt {
+        self.mul(a, a)
+    }
+
+    pub fn pow(&self, a: &UInt, exp: &UInt) -> UInt {
+        sliding_window_pow(a, exp, self.one(), |x, y| self.mul(x, y))
+    }
+
+    // operands at or above the modulus would break the bound of `redc`
+    fn reduced<'a>(&self, a: &'a UInt) -> Cow<'a, UInt> {
+        if *a >= self.modulus {
+            Cow::Owned(a.rem_ref(&self.modulus))
+        } else {
+            Cow::Borrowed(a)
+        }
+    }
+
+    // t * R^-1 mod modulus for t < modulus * R
+    fn redc(&self, t: &[u64]) -> UInt {
+        let m = &self.modulus._limbs[..self.modulus.limb_len()];
+        let k = m.len();
+        assert!(t.len() <= 2 * k, "Montgomery reduction input out of range");
+        let mut t = t.to_vec();
+        t.resize(2 * k + 1, 0);
+        for i in 0..k {
+            // adding u * modulus clears limb i
+            let u = t[i].wrapping_mul(self.inv);
+            let mut carry = 0u128;
+            for (j, &mj) in m.iter().enumerate() {
+                let v = t[i + j] as u128 + u as u128 * mj as u128 + carry;
+                t[i + j] = v as u64;
+                carry = v >> LIMB_BITS;
+            }
+            for limb in t[i + k..].iter_mut() {
+                if carry == 0 {
+                    break;
+                }
+                let v = *limb as u128 + carry;
+                *limb = v as u64;
+                carry = v >> LIMB_BITS;
+            }
+        }
+        let res = UInt::from_raw_limbs(t.split_off(k));
+        if res >= self.modulus {
+            res.sub_ref(&self.modulus)
+        } else {
+            res
+        }
+    }
+}
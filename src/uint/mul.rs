@@ -0,0 +1,203 @@
+use super::{ntt, UInt, LIMB_BITS};
+use crate::Int;
+
+// operand sizes in limbs, compared against the shorter operand
+const KARATSUBA_THRESHOLD: usize = 32;
+const TOOM3_THRESHOLD: usize = 256;
+const NTT_THRESHOLD: usize = 20_000;
+
+// smallest sizes for which splitting makes the operands strictly shorter
+const KARATSUBA_MIN: usize = 4;
+const TOOM3_MIN: usize = 3;
+
+// the sizes in limbs at which `mul_with` switches algorithm;
+// the operators always use the defaults
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MulConfig {
+    pub karatsuba: usize,
+    pub toom3: usize,
+    pub ntt: usize,
+}
+
+impl Default for MulConfig {
+    fn default() -> Self {
+        MulConfig {
+            karatsuba: KARATSUBA_THRESHOLD,
+            toom3: TOOM3_THRESHOLD,
+            ntt: NTT_THRESHOLD,
+        }
+    }
+}
+
+impl UInt {
+    pub fn mul_with(&self, rhs: &Self, config: &MulConfig) -> Self {
+        let a = &self._limbs[..self.limb_len()];
+        let b = &rhs._limbs[..rhs.limb_len()];
+        UInt::from_raw_limbs(mul_limbs(a, b, config))
+    }
+
+    pub(super) fn mul_ref(&self, rhs: &Self) -> Self {
+        self.mul_with(rhs, &MulConfig::default())
+    }
+}
+
+fn trimmed(a: &[u64]) -> &[u64] {
+    let len = a.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+    &a[..len]
+}
+
+pub(super) fn mul_limbs(a: &[u64], b: &[u64], config: &MulConfig) -> Vec<u64> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        return vec![];
+    }
+    if b.len() < config.karatsuba.max(KARATSUBA_MIN) {
+        return schoolbook(a, b);
+    }
+    if a.len() >= 2 * b.len() {
+        // cut the longer operand into pieces as long as the shorter one
+        let mut res = vec![0; a.len() + b.len()];
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            add_at(&mut res, &mul_limbs(chunk, b, config), i * b.len());
+        }
+        return res;
+    }
+    if b.len() >= config.ntt && ntt::fits_ntt(a.len(), b.len()) {
+        return ntt::mul_limbs_ntt(a, b);
+    }
+    if b.len() >= config.toom3.max(TOOM3_MIN) {
+        return toom3(a, b, config);
+    }
+    karatsuba(a, b, config)
+}
+
+fn schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u128 * y as u128 + res[i + j] as u128 + carry;
+            res[i + j] = t as u64;
+            carry = t >> LIMB_BITS;
+        }
+        res[i + b.len()] = carry as u64;
+    }
+    res
+}
+
+// acc += x << (offset limbs), growing acc if needed
+fn add_at(acc: &mut Vec<u64>, x: &[u64], offset: usize) {
+    if acc.len() < offset + x.len() + 1 {
+        acc.resize(offset + x.len() + 1, 0);
+    }
+    let mut carry = false;
+    for (i, limb) in acc.iter_mut().enumerate().skip(offset) {
+        let y = *x.get(i - offset).unwrap_or(&0);
+        if i - offset >= x.len() && !carry {
+            break;
+        }
+        let (s1, c1) = limb.overflowing_add(y);
+        let (s2, c2) = s1.overflowing_add(u64::from(carry));
+        *limb = s2;
+        carry = c1 || c2;
+    }
+    if carry {
+        acc.push(1);
+    }
+}
+
+// acc -= x, where acc >= x
+fn sub_assign(acc: &mut [u64], x: &[u64]) {
+    let mut borrow = false;
+    for (i, limb) in acc.iter_mut().enumerate() {
+        let y = *x.get(i).unwrap_or(&0);
+        if i >= x.len() && !borrow {
+            break;
+        }
+        let (d1, b1) = limb.overflowing_sub(y);
+        let (d2, b2) = d1.overflowing_sub(u64::from(borrow));
+        *limb = d2;
+        borrow = b1 || b2;
+    }
+}
+
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = a.to_vec();
+    add_at(&mut res, b, 0);
+    res
+}
+
+// a.len() >= b.len() > a.len() / 2
+fn karatsuba(a: &[u64], b: &[u64], config: &MulConfig) -> Vec<u64> {
+    let m = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = mul_limbs(a0, b0, config);
+    let z2 = mul_limbs(a1, b1, config);
+    // z1 = (a0 + a1)(b0 + b1) - z0 - z2
+    let mut z1 = mul_limbs(&add(a0, a1), &add(b0, b1), config);
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+    let mut res = z0;
+    res.resize(a.len() + b.len(), 0);
+    add_at(&mut res, trimmed(&z1), m);
+    add_at(&mut res, trimmed(&z2), 2 * m);
+    res
+}
+
+// Toom-Cook 3-way split, evaluated at 0, 1, -1, -2 and infinity
+fn toom3(a: &[u64], b: &[u64], config: &MulConfig) -> Vec<u64> {
+    let k = a.len().div_ceil(3);
+    let split = |x: &[u64]| -> [Int; 3] {
+        let part = |i: usize| {
+            let lo = (i * k).min(x.len());
+            let hi = ((i + 1) * k).min(x.len());
+            Int::from(UInt::from_limbs(&x[lo..hi]))
+        };
+        [part(0), part(1), part(2)]
+    };
+    let evaluate = |[x0, x1, x2]: [Int; 3]| -> [Int; 5] {
+        let p0 = x0.clone() + x2.clone();
+        let p1 = p0.clone() + x1.clone();
+        let pm1 = p0 - x1;
+        let pm2 = (pm1.clone() + x2.clone()) * Int::from(2) - x0.clone();
+        [x0, p1, pm1, pm2, x2]
+    };
+    let [p0, p1, pm1, pm2, pinf] = evaluate(split(a));
+    let [q0, q1, qm1, qm2, qinf] = evaluate(split(b));
+    // the pointwise products recurse with the same thresholds
+    let mul = |p: Int, q: Int| {
+        let negative = p.is_negative() != q.is_negative();
+        Int::new(negative, p.magnitude().mul_with(q.magnitude(), config))
+    };
+    let r0 = mul(p0, q0);
+    let r1 = mul(p1, q1);
+    let rm1 = mul(pm1, qm1);
+    let rm2 = mul(pm2, qm2);
+    let r4 = mul(pinf, qinf);
+    // interpolation (Bodrato's sequence)
+    let r3 = exact_div3(rm2 - r1.clone());
+    let r1 = half(r1 - rm1.clone());
+    let r2 = rm1 - r0.clone();
+    let r3 = half(r2.clone() - r3) + r4.clone() * Int::from(2);
+    let r2 = r2 + r1.clone() - r4.clone();
+    let r1 = r1 - r3.clone();
+    let mut res = vec![0; a.len() + b.len()];
+    for (i, r) in [r0, r1, r2, r3, r4].into_iter().enumerate() {
+        debug_assert!(!r.is_negative());
+        let r = r.unsigned_abs();
+        add_at(&mut res, &r._limbs[..r.limb_len()], i * k);
+    }
+    res
+}
+
+fn exact_div3(n: Int) -> Int {
+    let (negative, magnitude) = n.into_parts();
+    Int::new(negative, magnitude.div_rem_digit(3).0)
+}
+
+fn half(n: Int) -> Int {
+    let (negative, magnitude) = n.into_parts();
+    Int::new(negative, magnitude.shr_bits(1))
+}
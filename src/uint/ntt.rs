@@ -0,0 +1,203 @@
+use super::mul::{mul_limbs, MulConfig};
+use super::UInt;
+
+// NTT-friendly primes p = c * 2^k + 1, all with primitive root 3
+const P1: u64 = 998_244_353;
+const P2: u64 = 167_772_161;
+const P3: u64 = 469_762_049;
+const ROOT: u64 = 3;
+// the smallest 2-adic order among the primes
+const MAX_LOG_LEN: u32 = 23;
+// every coefficient of the product is below 2^23 * 2^48 < P1 * P2 * P3,
+// so it is recovered exactly from the three residues
+const COEF_BITS: usize = 24;
+const COEF_MASK: u64 = (1 << COEF_BITS) - 1;
+
+impl UInt {
+    pub fn mul_ntt(&self, rhs: &Self) -> Self {
+        let a = &self._limbs[..self.limb_len()];
+        let b = &rhs._limbs[..rhs.limb_len()];
+        if !fits_ntt(a.len(), b.len()) {
+            // let the other algorithms split the operands first
+            return UInt::from_raw_limbs(mul_limbs(a, b, &MulConfig::default()));
+        }
+        UInt::from_raw_limbs(mul_limbs_ntt(a, b))
+    }
+}
+
+fn coef_len(limbs: usize) -> usize {
+    (limbs * u64::BITS as usize).div_ceil(COEF_BITS)
+}
+
+pub(super) fn fits_ntt(a_len: usize, b_len: usize) -> bool {
+    coef_len(a_len) + coef_len(b_len) <= 1 << MAX_LOG_LEN
+}
+
+pub(super) fn mul_limbs_ntt(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let ca = to_coefs(a);
+    let cb = to_coefs(b);
+    let coefs = ca.len() + cb.len() - 1;
+    let len = coefs.next_power_of_two();
+    let res1 = convolve::<P1>(&ca, &cb, len);
+    let res2 = convolve::<P2>(&ca, &cb, len);
+    let res3 = convolve::<P3>(&ca, &cb, len);
+    let p1_inv = pow_mod::<P2>(P1 % P2, P2 - 2);
+    let p12_inv = pow_mod::<P3>(P1 * P2 % P3, P3 - 2);
+    // Garner's algorithm, carrying every coefficient into COEF_BITS bits
+    let mut chunks = Vec::with_capacity(coefs + 4);
+    let mut carry: u128 = 0;
+    let residues = res1.into_iter().zip(res2).zip(res3);
+    for ((r1, r2), r3) in residues.take(coefs) {
+        let t = (r2 + P2 - r1 % P2) % P2 * p1_inv % P2;
+        let x12 = r1 + P1 * t;
+        let t = (r3 + P3 - x12 % P3) % P3 * p12_inv % P3;
+        carry += x12 as u128 + (P1 * P2) as u128 * t as u128;
+        chunks.push(carry as u64 & COEF_MASK);
+        carry >>= COEF_BITS;
+    }
+    while carry > 0 {
+        chunks.push(carry as u64 & COEF_MASK);
+        carry >>= COEF_BITS;
+    }
+    from_coefs(&chunks)
+}
+
+fn to_coefs(limbs: &[u64]) -> Vec<u64> {
+    let bits = limbs.len() * u64::BITS as usize;
+    (0..bits.div_ceil(COEF_BITS))
+        .map(|i| {
+            let (q, r) = (i * COEF_BITS / 64, i * COEF_BITS % 64);
+            let lo = limbs[q] >> r;
+            let hi = match limbs.get(q + 1) {
+                Some(&next) if r + COEF_BITS > 64 => next << (64 - r),
+                _ => 0,
+            };
+            (lo | hi) & COEF_MASK
+        })
+        .collect()
+}
+
+fn from_coefs(coefs: &[u64]) -> Vec<u64> {
+    let mut limbs = vec![0; (coefs.len() * COEF_BITS).div_ceil(64)];
+    for (i, &c) in coefs.iter().enumerate() {
+        let (q, r) = (i * COEF_BITS / 64, i * COEF_BITS % 64);
+        limbs[q] |= c << r;
+        if r + COEF_BITS > 64 {
+            limbs[q + 1] |= c >> (64 - r);
+        }
+    }
+    limbs
+}
+
+fn convolve<const P: u64>(a: &[u64], b: &[u64], len: usize) -> Vec<u64> {
+    let mut fa: Vec<u32> = a.iter().map(|&x| x as u32).collect();
+    let mut fb: Vec<u32> = b.iter().map(|&x| x as u32).collect();
+    fa.resize(len, 0);
+    fb.resize(len, 0);
+    ntt::<P>(&mut fa, false);
+    ntt::<P>(&mut fb, false);
+    // the pointwise products pick up a factor R^-1,
+    // which is cancelled together with 1/len at the end
+    for (x, y) in fa.iter_mut().zip(fb.iter()) {
+        *x = Mont::<P>::mul(*x, *y);
+    }
+    ntt::<P>(&mut fa, true);
+    let n_inv = pow_mod::<P>(len as u64, P - 2) as u32;
+    let scale = Mont::<P>::to_mont(Mont::<P>::to_mont(n_inv));
+    fa.into_iter()
+        .map(|x| Mont::<P>::mul(x, scale) as u64)
+        .collect()
+}
+
+// iterative Cooley-Tukey transform over Z/PZ, `a.len()` must be a power of two;
+// the values stay in normal form and only the twiddles are in Montgomery form
+fn ntt<const P: u64>(a: &mut [u32], inverse: bool) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let p = P as u32;
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut len = 2;
+    while len <= n {
+        let mut w = pow_mod::<P>(ROOT, (P - 1) / len as u64);
+        if inverse {
+            w = pow_mod::<P>(w, P - 2);
+        }
+        let w = Mont::<P>::to_mont(w as u32);
+        let half = len / 2;
+        twiddles.clear();
+        twiddles.push(Mont::<P>::to_mont(1));
+        for k in 1..half {
+            twiddles.push(Mont::<P>::mul(twiddles[k - 1], w));
+        }
+        for block in a.chunks_exact_mut(len) {
+            let (lo, hi) = block.split_at_mut(half);
+            for ((u, v), &wn) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles.iter()) {
+                let x = *u;
+                let y = Mont::<P>::mul(*v, wn);
+                *u = if x + y >= p { x + y - p } else { x + y };
+                *v = if x >= y { x - y } else { x + p - y };
+            }
+        }
+        len <<= 1;
+    }
+}
+
+// Montgomery multiplication with R = 2^32, the primes are below 2^30
+struct Mont<const P: u64>;
+
+impl<const P: u64> Mont<P> {
+    // -P^-1 mod R by Newton's iteration
+    const NEG_INV: u32 = {
+        let mut inv: u32 = 1;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub((P as u32).wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    };
+    const R2: u32 = ((1u128 << 64) % P as u128) as u32;
+
+    // a * b * R^-1 mod P for a, b < P
+    fn mul(a: u32, b: u32) -> u32 {
+        let t = a as u64 * b as u64;
+        let m = (t as u32).wrapping_mul(Self::NEG_INV) as u64;
+        let u = ((t + m * P) >> 32) as u32;
+        if u >= P as u32 {
+            u - P as u32
+        } else {
+            u
+        }
+    }
+
+    fn to_mont(a: u32) -> u32 {
+        Self::mul(a, Self::R2)
+    }
+}
+
+fn pow_mod<const P: u64>(mut base: u64, mut exp: u64) -> u64 {
+    let mut res = 1;
+    base %= P;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = res * base % P;
+        }
+        base = base * base % P;
+        exp >>= 1;
+    }
+    res
+}
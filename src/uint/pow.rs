@@ -0,0 +1,83 @@
+use super::{MontgomeryCtx, UInt};
+use crate::Pow;
+
+impl UInt {
+    // takes every exponent type `Pow` does: u32, &UInt or UInt
+    pub fn pow<E>(&self, exp: E) -> Self
+    where
+        for<'a> &'a UInt: Pow<E, Output = UInt>,
+    {
+        Pow::pow(self, exp)
+    }
+
+    // square-and-multiply from the most significant bit of `exp`
+    pub fn pow_uint(&self, exp: &Self) -> Self {
+        let mut res = UInt::from(1u8);
+        for i in (0..exp.max_bit_digit()).rev() {
+            res = &res * &res;
+            if exp.get_bit(i) {
+                res = &res * self;
+            }
+        }
+        res
+    }
+
+    pub fn pow_mod(&self, exp: &Self, modulus: &Self) -> Self {
+        if modulus.get_bit(0) {
+            // odd moduli avoid division by working in Montgomery form
+            let ctx = MontgomeryCtx::new(modulus);
+            let base = ctx.to_montgomery(self);
+            return ctx.from_montgomery(&ctx.pow(&base, exp));
+        }
+        let one = UInt::from(1u8) % modulus;
+        sliding_window_pow(&(self % modulus), exp, one, |a, b| a * b % modulus)
+    }
+}
+
+// sliding window exponentiation over the multiplication `mul`,
+// where `one` is its identity
+pub(super) fn sliding_window_pow<F>(base: &UInt, exp: &UInt, one: UInt, mul: F) -> UInt
+where
+    F: Fn(&UInt, &UInt) -> UInt,
+{
+    let len = exp.max_bit_digit();
+    if len == 0 {
+        return one;
+    }
+    let window = match len {
+        0..=24 => 1,
+        25..=80 => 3,
+        81..=240 => 4,
+        241..=672 => 5,
+        _ => 6,
+    };
+    // odd powers: table[k] = base^(2k + 1)
+    let square = mul(base, base);
+    let mut table = vec![base.clone()];
+    for k in 1..1 << (window - 1) {
+        let next = mul(&table[k - 1], &square);
+        table.push(next);
+    }
+    let mut res = one;
+    let mut i = len;
+    while i > 0 {
+        if !exp.get_bit(i - 1) {
+            res = mul(&res, &res);
+            i -= 1;
+            continue;
+        }
+        // the longest window exp[j..i] that ends with a set bit
+        let mut j = i.saturating_sub(window);
+        while !exp.get_bit(j) {
+            j += 1;
+        }
+        let mut value = 0;
+        for k in (j..i).rev() {
+            res = mul(&res, &res);
+            value = value << 1 | usize::from(exp.get_bit(k));
+        }
+        res = mul(&res, &table[value >> 1]);
+        i = j;
+    }
+    res
+}
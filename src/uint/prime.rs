@@ -0,0 +1,215 @@
+use crate::xorshift::XorShift;
+
+use super::{UInt, LIMB_BITS};
+
+const SMALL_PRIMES: [u64; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+// Miller-Rabin with these bases is exact below 2^64
+const DETERMINISTIC_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+impl UInt {
+    // exact below 2^64; above that, a Baillie-PSW test followed by
+    // `rounds` Miller-Rabin rounds with pseudo-random bases
+    pub fn is_probable_prime(&self, rounds: u32) -> bool {
+        match self.trial_division() {
+            Some(is_prime) => return is_prime,
+            None if self.max_bit_digit() <= 64 => {
+                return DETERMINISTIC_BASES
+                    .iter()
+                    .all(|&a| self.miller_rabin(&UInt::from(a)));
+            }
+            None => {}
+        }
+        if !self.is_bpsw_prime() {
+            return false;
+        }
+        // bases in [2, self - 2]
+        let range = self - 3u64;
+        let mut rng = XorShift::new(self.limb(0) | 1);
+        (0..rounds).all(|_| {
+            let a = UInt::from_raw_limbs(rng.next_limbs(self.limb_len())) % &range + 2u64;
+            self.miller_rabin(&a)
+        })
+    }
+
+    // Miller-Rabin to base 2 followed by a strong Lucas test
+    pub fn is_bpsw_prime(&self) -> bool {
+        if let Some(is_prime) = self.trial_division() {
+            return is_prime;
+        }
+        self.miller_rabin(&UInt::from(2u8)) && self.strong_lucas()
+    }
+
+    // the smallest prime greater than `self`
+    pub fn next_prime(&self) -> Self {
+        if *self < UInt::from(2u8) {
+            return UInt::from(2u8);
+        }
+        // the next odd number
+        let mut n = self + 1u64;
+        if !n.get_bit(0) {
+            n += 1u64;
+        }
+        while !n.is_probable_prime(0) {
+            n += 2u64;
+        }
+        n
+    }
+
+    // the largest prime less than `self`
+    pub fn prev_prime(&self) -> Option<Self> {
+        if *self <= UInt::from(2u8) {
+            return None;
+        }
+        if *self == UInt::from(3u8) {
+            return Some(UInt::from(2u8));
+        }
+        // the previous odd number
+        let mut n = self - 1u64;
+        if !n.get_bit(0) {
+            n -= 1u64;
+        }
+        while !n.is_probable_prime(0) {
+            n -= 2u64;
+        }
+        Some(n)
+    }
+
+    // Some(answer) if divisibility by small primes decides primality
+    fn trial_division(&self) -> Option<bool> {
+        if self.max_bit_digit() <= LIMB_BITS && SMALL_PRIMES.contains(&self.limb(0)) {
+            return Some(true);
+        }
+        if *self < UInt::from(2u8) {
+            return Some(false);
+        }
+        if SMALL_PRIMES.iter().any(|&p| self.div_rem_digit(p).1 == 0) {
+            return Some(false);
+        }
+        // no prime factor up to 251
+        if *self < UInt::from(257u64 * 257) {
+            return Some(true);
+        }
+        None
+    }
+
+    // strong probable prime test to base `a` for odd `self` > 2
+    fn miller_rabin(&self, a: &Self) -> bool {
+        let n_minus_1 = self - 1u64;
+        let s = n_minus_1.trailing_zeros().unwrap_or(0);
+        let d = n_minus_1.shr_bits(s);
+        let mut x = a.pow_mod(&d, self);
+        if x == UInt::from(1u8) || x == n_minus_1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = &x * &x % self;
+            if x == n_minus_1 {
+                return true;
+            }
+        }
+        false
+    }
+
+    // strong Lucas probable prime test with Selfridge's parameters,
+    // for odd `self` that has no small prime factor
+    fn strong_lucas(&self) -> bool {
+        // the first D in 5, -7, 9, -11, ... with jacobi(D, self) == -1
+        let mut d = 5u64;
+        let mut negative = false;
+        let d_mod = loop {
+            let d_mod = if negative {
+                self - &(UInt::from(d) % self)
+            } else {
+                UInt::from(d) % self
+            };
+            match jacobi(&d_mod, self) {
+                -1 => break d_mod,
+                // self > |D| shares a factor with D
+                0 => return false,
+                _ => {}
+            }
+            // no such D exists for perfect squares
+            if d == 61 && self.is_perfect_square() {
+                return false;
+            }
+            d += 2;
+            negative = !negative;
+        };
+        // P = 1, Q = (1 - D) / 4
+        let q = if negative {
+            UInt::from((d + 1) / 4) % self
+        } else {
+            self - &(UInt::from((d - 1) / 4) % self)
+        };
+        let sub = |a: &UInt, b: &UInt| (a + &(self - &(b % self))) % self;
+        let half = |a: UInt| {
+            if a.get_bit(0) {
+                (a + self).shr_bits(1)
+            } else {
+                a.shr_bits(1)
+            }
+        };
+
+        let n_plus_1 = self + 1u64;
+        let s = n_plus_1.trailing_zeros().unwrap_or(0);
+        let k = n_plus_1.shr_bits(s);
+        // (U_1, V_1, Q^1)
+        let mut u = UInt::from(1u8);
+        let mut v = UInt::from(1u8);
+        let mut qk = q.clone();
+        for i in (0..k.max_bit_digit() - 1).rev() {
+            // doubling: U_2k = U_k V_k, V_2k = V_k^2 - 2 Q^k
+            u = &u * &v % self;
+            v = sub(&(&v * &v % self), &(&qk << 1usize));
+            qk = &qk * &qk % self;
+            if k.get_bit(i) {
+                // U_2k+1 = (U_2k + V_2k) / 2, V_2k+1 = (D U_2k + V_2k) / 2
+                let u_next = half(&u + &v);
+                v = half(&(&d_mod * &u) + &v) % self;
+                u = u_next % self;
+                qk = &qk * &q % self;
+            }
+        }
+        if u.is_zero() || v.is_zero() {
+            return true;
+        }
+        for _ in 1..s {
+            v = sub(&(&v * &v % self), &(&qk << 1usize));
+            if v.is_zero() {
+                return true;
+            }
+            qk = &qk * &qk % self;
+        }
+        false
+    }
+}
+
+// Jacobi symbol (a / n) for odd n
+fn jacobi(a: &UInt, n: &UInt) -> i8 {
+    let mut a = a % n;
+    let mut n = n.clone();
+    let mut result = 1;
+    while !a.is_zero() {
+        let z = a.trailing_zeros().unwrap_or(0);
+        a = a.shr_bits(z);
+        // (2 / n) == -1 iff n == 3, 5 (mod 8)
+        if z % 2 == 1 && matches!(n.limb(0) & 7, 3 | 5) {
+            result = -result;
+        }
+        // quadratic reciprocity
+        if a.limb(0) & 3 == 3 && n.limb(0) & 3 == 3 {
+            result = -result;
+        }
+        (a, n) = (&n % &a, a);
+    }
+    if n == UInt::from(1u8) {
+        result
+    } else {
+        0
+    }
+}
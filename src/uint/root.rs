@@ -0,0 +1,62 @@
+use super::UInt;
+
+impl UInt {
+    // floor of the square root by Newton's iteration
+    pub fn isqrt(&self) -> Self {
+        if self.is_zero() {
+            return UInt::new([]);
+        }
+        // start above the root so the iteration decreases monotonically
+        let mut x = UInt::from(1u8).shl_bits(self.max_bit_digit().div_ceil(2));
+        loop {
+            let y = (&x + &(self / &x)).shr_bits(1);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    pub fn sqrt_rem(&self) -> (Self, Self) {
+        let s = self.isqrt();
+        let r = self - &(&s * &s);
+        (s, r)
+    }
+
+    pub fn is_perfect_square(&self) -> bool {
+        // squares are 0, 1, 4 or 9 modulo 16
+        if !matches!(self.limb(0) & 15, 0 | 1 | 4 | 9) {
+            return false;
+        }
+        self.sqrt_rem().1.is_zero()
+    }
+
+    // floor of the n-th root by Newton's iteration
+    pub fn nth_root(&self, n: u32) -> Self {
+        match n {
+            0 => panic!("attempt to take the zeroth root"),
+            1 => return self.clone(),
+            2 => return self.isqrt(),
+            _ => {}
+        }
+        let len = self.max_bit_digit();
+        if len == 0 {
+            return UInt::new([]);
+        }
+        // self < 2^len <= 2^n, so the root is 1
+        if len <= n as usize {
+            return UInt::from(1u8);
+        }
+        let n_minus_1 = UInt::from(n - 1);
+        let n = UInt::from(n);
+        let mut x = UInt::from(1u8).shl_bits(len.div_ceil(n.limb(0) as usize));
+        loop {
+            let t = self / &x.pow_uint(&n_minus_1);
+            let y = (&(&n_minus_1 * &x) + &t) / &n;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+}
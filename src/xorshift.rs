@@ -0,0 +1,21 @@
+// xorshift64 generator for deterministic pseudo random choices,
+// shared with the tests through tests/common
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "xorshift needs a nonzero seed");
+        XorShift(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn next_limbs(&mut self, limbs: usize) -> Vec<u64> {
+        (0..limbs).map(|_| self.next_u64()).collect()
+    }
+}
@@ -0,0 +1,251 @@
+mod common;
+
+#[cfg(test)]
+mod big_float {
+    use crate::common::XorShift;
+    use binum::{BigFloat, BigFloatErrorKind, Rational, RoundingMode, UInt, F64};
+
+    const EVEN: RoundingMode = RoundingMode::NearestTiesToEven;
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestTiesToEven,
+        RoundingMode::NearestTiesToAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+    ];
+
+    fn from_f64(x: f64, precision: usize) -> BigFloat {
+        BigFloat::from_rational(&Rational::from_f64(x).unwrap(), precision, EVEN)
+    }
+
+    fn to_f64(x: &BigFloat) -> f64 {
+        x.to_rational().to_f64()
+    }
+
+    fn random_f64(rng: &mut XorShift) -> f64 {
+        let r = rng.next_u64();
+        let sign = r >> 63 << 63;
+        // exponents around 1.0, away from overflow and subnormals
+        f64::from_bits(sign | (r >> 12 & ((1 << 52) - 1)) | (1000 + r % 48) << 52)
+    }
+
+    #[test]
+    fn test_basics() {
+        let x = BigFloat::from_uint(&UInt::from(12u64), 8, EVEN);
+        assert_eq!(x.mantissa(), &UInt::from(3u64));
+        assert_eq!(x.exponent(), 2);
+        assert_eq!(x.precision(), 8);
+        assert!(BigFloat::zero(10).is_zero());
+        let y = BigFloat::from_uint(&UInt::from(257u64), 8, EVEN);
+        assert_eq!(y.to_rational(), Rational::from(256u64));
+        let y = BigFloat::from_uint(&UInt::from(257u64), 8, RoundingMode::TowardPositive);
+        assert_eq!(y.to_rational(), Rational::from(258u64));
+        assert!(x < y);
+        assert!(-y.clone() < x);
+        assert_eq!(x.with_precision(100, EVEN), x);
+        assert_eq!((-x.clone()).abs(), x);
+    }
+
+    #[test]
+    fn test_arith_f64() {
+        // at 53 bits every operation rounds like f64
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..300 {
+            let (a, b) = (random_f64(&mut rng), random_f64(&mut rng));
+            let (x, y) = (from_f64(a, 53), from_f64(b, 53));
+            assert_eq!(to_f64(&(x.clone() + y.clone())), a + b, "{a:e} + {b:e}");
+            assert_eq!(to_f64(&(x.clone() - y.clone())), a - b, "{a:e} - {b:e}");
+            assert_eq!(to_f64(&(x.clone() * y.clone())), a * b, "{a:e} * {b:e}");
+            assert_eq!(to_f64(&(x.clone() / y.clone())), a / b, "{a:e} / {b:e}");
+            assert_eq!(to_f64(&x.abs().sqrt()), a.abs().sqrt(), "sqrt({a:e})");
+            let (fa, fb) = (F64::from(a), F64::from(b));
+            for mode in MODES {
+                let expected = f64::from(fa.add_with(fb, mode));
+                assert_eq!(to_f64(&x.add_with(&y, mode)), expected);
+                let expected = f64::from(fa.div_with(fb, mode));
+                assert_eq!(to_f64(&x.div_with(&y, mode)), expected);
+                let expected = f64::from(fa.abs().sqrt_with(mode));
+                assert_eq!(to_f64(&x.abs().sqrt_with(mode)), expected);
+            }
+        }
+        let mut z = from_f64(1.5, 53);
+        z += from_f64(2.0, 53);
+        z *= from_f64(4.0, 53);
+        z -= from_f64(1.0, 53);
+        z /= from_f64(2.0, 53);
+        assert_eq!(to_f64(&z), 6.5);
+    }
+
+    #[test]
+    fn test_far_operands() {
+        let one = from_f64(1.0, 53);
+        let tiny = BigFloat::from_rational(
+            &Rational::new(false, UInt::from(1u64), UInt::from(1u64) << 1000usize),
+            53,
+            EVEN,
+        );
+        assert_eq!(one.add_with(&tiny, EVEN), one);
+        assert_eq!(
+            to_f64(&one.add_with(&tiny, RoundingMode::TowardPositive)),
+            1.0 + f64::EPSILON
+        );
+        assert_eq!(
+            to_f64(&one.sub_with(&tiny, RoundingMode::TowardZero)),
+            1.0 - f64::EPSILON / 2.0
+        );
+        assert_eq!(one.sub_with(&tiny, RoundingMode::TowardPositive), one);
+        assert!((one.clone() - one).is_zero());
+    }
+
+    #[test]
+    fn test_parse() {
+        for s in [
+            "0.1",
+            "-2.5",
+            "1e-3",
+            "123456789.987654321",
+            ".5",
+            "7.",
+            "6.02214076E23",
+        ] {
+            let x = BigFloat::parse(s, 53, EVEN).unwrap();
+            assert_eq!(to_f64(&x), s.parse::<f64>().unwrap(), "{s}");
+        }
+        let down = BigFloat::parse("0.1", 53, RoundingMode::TowardZero).unwrap();
+        let up = BigFloat::parse("0.1", 53, RoundingMode::TowardPositive).unwrap();
+        assert_eq!(to_f64(&down), 0.09999999999999999);
+        assert_eq!(to_f64(&up), 0.1);
+        for s in ["", "-", ".", "1.2.3", "e5", "1e", "0x10", "1/2"] {
+            assert!(BigFloat::parse(s, 53, EVEN).is_err(), "{s:?}");
+        }
+        assert_eq!(
+            BigFloat::parse("", 53, EVEN).unwrap_err().to_string(),
+            "cannot parse float from empty string"
+        );
+        assert_eq!(
+            BigFloat::parse("x", 53, EVEN).unwrap_err().to_string(),
+            "invalid float literal"
+        );
+        let kind = |s: &str| *BigFloat::parse(s, 53, EVEN).unwrap_err().kind();
+        assert_eq!(kind(""), BigFloatErrorKind::Empty);
+        assert_eq!(kind("1.2.3"), BigFloatErrorKind::Invalid);
+        // exponents are range checked instead of truncated or overflowing
+        for s in [
+            "1e2000000000000000000",
+            "1.5e-9223372036854775808",
+            "1e9223372036854775808",
+            "1e99999999999999999999",
+        ] {
+            assert_eq!(kind(s), BigFloatErrorKind::ExponentOutOfRange, "{s}");
+        }
+        assert_eq!(
+            BigFloat::parse("1e9223372036854775807", 53, EVEN)
+                .unwrap_err()
+                .to_string(),
+            "exponent out of range"
+        );
+        assert_eq!(to_f64(&BigFloat::parse("25e-1", 53, EVEN).unwrap()), 2.5);
+    }
+
+    #[test]
+    fn test_parse_large_exponents() {
+        // far from the exact range these take a few dozen multiplications
+        for s in [
+            "1.7976931348623157e308",
+            "2.2250738585072014e-308",
+            "1e23",
+            "8.98846567431158e307",
+        ] {
+            let x = BigFloat::parse(s, 53, EVEN).unwrap();
+            assert_eq!(to_f64(&x), s.parse::<f64>().unwrap(), "{s}");
+        }
+        let digits = |s: &str, mode| {
+            BigFloat::parse(s, 64, mode)
+                .unwrap()
+                .to_decimal_string(12, EVEN)
+        };
+        assert_eq!(digits("1e1000000", EVEN), "1.00000000000e1000000");
+        assert_eq!(digits("-3.7e-1234567", EVEN), "-3.70000000000e-1234567");
+        assert_eq!(digits("1e4294967296", EVEN), "1.00000000000e4294967296");
+        assert_eq!(
+            digits("25e-9999999", RoundingMode::TowardZero),
+            "2.50000000000e-9999998"
+        );
+        let down = BigFloat::parse("1e-1000000", 64, RoundingMode::TowardZero).unwrap();
+        let up = BigFloat::parse("1e-1000000", 64, RoundingMode::TowardPositive).unwrap();
+        // the value is inexact, so the directed roundings differ
+        assert!(down < up);
+        for x in [down, up] {
+            assert_eq!(x.to_decimal_string(12, EVEN), "1.00000000000e-1000000");
+        }
+        // decimal exponents past u32 format without an exact power of ten
+        let huge = BigFloat::parse("-4.25e1000000000000000", 64, EVEN).unwrap();
+        assert_eq!(huge.to_decimal_string(5, EVEN), "-4.2500e1000000000000000");
+        let tiny = BigFloat::parse("4.25e-1000000000000000", 64, EVEN).unwrap();
+        assert_eq!(tiny.to_decimal_string(3, EVEN), "4.25e-1000000000000000");
+        // digits past the exact expansion are zeros
+        assert_eq!(
+            from_f64(0.375, 53).to_decimal_string(30, EVEN),
+            format!("3.75{}e-1", "0".repeat(27))
+        );
+        assert_eq!(
+            from_f64(2f64.powi(70), 53).to_decimal_string(25, EVEN),
+            "1.180591620717411303424000e21"
+        );
+    }
+
+    #[test]
+    fn test_to_string() {
+        let x = from_f64(0.1, 53);
+        assert_eq!(x.to_string(), "1.0000000000000001e-1");
+        assert_eq!(x.to_decimal_string(3, EVEN), "1.00e-1");
+        assert_eq!(from_f64(-1234.5, 53).to_decimal_string(4, EVEN), "-1.234e3");
+        assert_eq!(
+            from_f64(-1234.5, 53).to_decimal_string(4, RoundingMode::NearestTiesToAway),
+            "-1.235e3"
+        );
+        assert_eq!(
+            from_f64(9.99, 53).to_decimal_string(2, RoundingMode::TowardPositive),
+            "1.0e1"
+        );
+        assert_eq!(
+            from_f64(9.99, 53).to_decimal_string(2, RoundingMode::TowardZero),
+            "9.9e0"
+        );
+        assert_eq!(from_f64(5.0, 53).to_decimal_string(1, EVEN), "5e0");
+        assert_eq!(BigFloat::zero(8).to_decimal_string(3, EVEN), "0.00e0");
+        assert_eq!(format!("{:>9}", from_f64(0.5, 4)), "  5.00e-1");
+        assert_eq!(format!("{}", from_f64(-0.5, 4)), "-5.00e-1");
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(to_f64(&BigFloat::pi(53)), std::f64::consts::PI);
+        assert_eq!(to_f64(&BigFloat::e(53)), std::f64::consts::E);
+        assert_eq!(
+            BigFloat::pi(256).to_decimal_string(60, EVEN),
+            "3.14159265358979323846264338327950288419716939937510582097494e0"
+        );
+        assert_eq!(
+            BigFloat::e(256).to_decimal_string(60, EVEN),
+            "2.71828182845904523536028747135266249775724709369995957496697e0"
+        );
+        let two = BigFloat::from_uint(&UInt::from(2u64), 256, EVEN);
+        assert_eq!(
+            two.sqrt().to_decimal_string(60, EVEN),
+            "1.41421356237309504880168872420969807856967187537694807317668e0"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_zero() {
+        let _ = from_f64(1.0, 53) / BigFloat::zero(53);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_negative() {
+        let _ = from_f64(-1.0, 53).sqrt();
+    }
+}
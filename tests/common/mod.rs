@@ -0,0 +1,17 @@
+// helpers shared by the test binaries, each of which uses only some of them
+#![allow(dead_code)]
+
+use binum::UInt;
+
+#[path = "../../src/xorshift.rs"]
+mod xorshift;
+pub use xorshift::XorShift;
+
+impl XorShift {
+    // `limbs` random limbs, trimmed
+    pub fn next_uint(&mut self, limbs: usize) -> UInt {
+        let mut n = UInt::from_limbs(self.next_limbs(limbs));
+        n.trim_mut();
+        n
+    }
+}
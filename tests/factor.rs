@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod factor {
+    use binum::num_theory::{factor, factor_with_limit};
+    use binum::UInt;
+
+    fn naive_factor(mut n: u64) -> Vec<(UInt, u32)> {
+        let mut res = Vec::new();
+        let mut d = 2;
+        while d * d <= n {
+            let mut e = 0;
+            while n.is_multiple_of(d) {
+                n /= d;
+                e += 1;
+            }
+            if e > 0 {
+                res.push((UInt::from(d), e));
+            }
+            d += 1;
+        }
+        if n > 1 {
+            res.push((UInt::from(n), 1));
+        }
+        res
+    }
+
+    fn product(factors: &[(UInt, u32)]) -> UInt {
+        factors
+            .iter()
+            .fold(UInt::from(1u64), |acc, (p, e)| acc * p.pow(*e))
+    }
+
+    #[test]
+    fn test_small() {
+        assert!(factor(&UInt::from(1u64)).is_empty());
+        for n in 2..3000u64 {
+            assert_eq!(factor(&UInt::from(n)), naive_factor(n), "{n}");
+        }
+        let n = 1_000_003u64 * 999_983;
+        assert_eq!(factor(&UInt::from(n)), naive_factor(n));
+    }
+
+    #[test]
+    fn test_large() {
+        let factors = factor(&UInt::from(u64::MAX));
+        let expected: Vec<_> = [3u64, 5, 17, 257, 641, 65537, 6700417]
+            .into_iter()
+            .map(|p| (UInt::from(p), 1))
+            .collect();
+        assert_eq!(factors, expected);
+
+        // 2^67 - 1
+        let n = (UInt::from(1u64) << 67usize) - 1u64;
+        let expected = vec![
+            (UInt::from(193707721u64), 1),
+            (UInt::from(761838257287u64), 1),
+        ];
+        assert_eq!(factor(&n), expected);
+
+        let p = UInt::from(1_000_000_007u64);
+        let q = UInt::from(998_244_353u64);
+        let n = &p * &p * &q * 12u64;
+        let factors = factor(&n);
+        assert_eq!(
+            factors,
+            vec![(UInt::from(2u64), 2), (UInt::from(3u64), 1), (q, 1), (p, 2)]
+        );
+        assert_eq!(product(&factors), n);
+    }
+
+    #[test]
+    fn test_perfect_power() {
+        let n = UInt::from(1009u64).pow(5) * UInt::from(1013u64).pow(2);
+        assert_eq!(
+            factor(&n),
+            vec![(UInt::from(1009u64), 5), (UInt::from(1013u64), 2)]
+        );
+        let n = (UInt::from(65537u64) * 65539u64).pow(3);
+        assert_eq!(
+            factor(&n),
+            vec![(UInt::from(65537u64), 3), (UInt::from(65539u64), 3)]
+        );
+    }
+
+    #[test]
+    fn test_work_limit() {
+        let p = (UInt::from(1u64) << 61usize) - 1u64;
+        let q = (UInt::from(1u64) << 89usize) - 1u64;
+        let n = &p * &q * 10u64;
+        let partial = factor_with_limit(&n, 10);
+        assert!(!partial.is_complete());
+        assert_eq!(
+            partial.factors(),
+            &[(UInt::from(2u64), 1), (UInt::from(5u64), 1)]
+        );
+        assert_eq!(partial.remaining(), &[&p * &q]);
+
+        let full = factor_with_limit(&UInt::from(u64::MAX), 1_000_000);
+        assert!(full.is_complete());
+        assert_eq!(product(full.factors()), UInt::from(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_factor_zero() {
+        let _ = factor(&UInt::from(0u64));
+    }
+}
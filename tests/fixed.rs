@@ -0,0 +1,164 @@
+mod common;
+
+#[cfg(test)]
+mod fixed {
+    use crate::common::XorShift;
+    use binum::{Fixed, Int, Rational, RoundingMode, UInt};
+
+    type Q15 = Fixed<15>;
+
+    fn q15(raw: i64) -> Q15 {
+        Q15::from_raw(Int::from(raw))
+    }
+
+    fn raw(x: &Q15) -> i64 {
+        x.raw().to_string().parse().unwrap()
+    }
+
+    fn random_i16(rng: &mut XorShift) -> i64 {
+        rng.next_u64() as i16 as i64
+    }
+
+    #[test]
+    fn test_basics() {
+        let x = Q15::from(3u64);
+        assert_eq!(raw(&x), 3 << 15);
+        assert_eq!(Q15::FRAC, 15);
+        assert_eq!(x.floor(), Int::from(3));
+        assert_eq!(q15(-1).floor(), Int::from(-1));
+        assert_eq!(q15(-(1 << 14)).abs(), q15(1 << 14));
+        assert!(q15(-1) < q15(0));
+        assert!(Q15::from(0u64).is_zero());
+        assert_eq!(
+            q15(3 << 13).to_rational(),
+            Rational::new(false, UInt::from(3u64), UInt::from(4u64))
+        );
+        let mut y = Q15::from(1u64);
+        y += q15(1 << 14);
+        y -= Q15::from(2u64);
+        assert_eq!(y, q15(-(1 << 14)));
+        assert_eq!(-y, q15(1 << 14));
+    }
+
+    #[test]
+    fn test_mul_matches_q15() {
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..2000 {
+            let (a, b) = (random_i16(&mut rng), random_i16(&mut rng));
+            let (x, y) = (q15(a), q15(b));
+            let p = a * b;
+            // arithmetic shift truncates toward negative infinity
+            assert_eq!(raw(&(x.clone() * y.clone())), p >> 15);
+            let down = x.mul_with(&y, RoundingMode::TowardNegative);
+            assert_eq!(raw(&down), p >> 15);
+            let up = x.mul_with(&y, RoundingMode::TowardPositive);
+            assert_eq!(raw(&up), (p + (1 << 15) - 1) >> 15);
+            let zero = x.mul_with(&y, RoundingMode::TowardZero);
+            assert_eq!(raw(&zero), p / (1 << 15));
+            let away = x.mul_with(&y, RoundingMode::NearestTiesToAway);
+            assert_eq!(raw(&away), p.signum() * ((p.abs() + (1 << 14)) >> 15));
+            let even = x.mul_with(&y, RoundingMode::NearestTiesToEven);
+            let expected = match p & 0xffff {
+                0x4000 => p >> 15,
+                0xc000 => (p >> 15) + 1,
+                _ => (p + (1 << 14)) >> 15,
+            };
+            assert_eq!(raw(&even), expected, "{a} * {b}");
+            // a 16 bit register wraps or saturates
+            let wrapped = (p >> 15) as i16 as i64;
+            assert_eq!(raw(&down.wrapping_to(16)), wrapped);
+            let saturated = (p >> 15).clamp(i16::MIN as i64, i16::MAX as i64);
+            assert_eq!(raw(&down.saturating_to(16)), saturated);
+        }
+    }
+
+    #[test]
+    fn test_div_matches_q15() {
+        let mut rng = XorShift::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..2000 {
+            let (a, b) = (random_i16(&mut rng), random_i16(&mut rng));
+            if b == 0 {
+                continue;
+            }
+            let (x, y) = (q15(a), q15(b));
+            let n = a << 15;
+            assert_eq!(raw(&x.div_with(&y, RoundingMode::TowardZero)), n / b);
+            let floor = n / b - i64::from(n % b != 0 && (n < 0) != (b < 0));
+            assert_eq!(raw(&(x.clone() / y.clone())), floor);
+            let even = x.div_with(&y, RoundingMode::NearestTiesToEven);
+            let diff = (Rational::from(raw(&even)) - Rational::from(n) / Rational::from(b)).abs();
+            assert!(diff <= Rational::new(false, UInt::from(1u64), UInt::from(2u64)));
+        }
+        let mut z = Q15::from(3u64);
+        z /= Q15::from(4u64);
+        assert_eq!(raw(&z), 3 << 13);
+        z *= Q15::from(2u64);
+        assert_eq!(raw(&z), 3 << 14);
+    }
+
+    #[test]
+    fn test_registers() {
+        let x = Q15::from(1u64);
+        assert_eq!(raw(&x.wrapping_to(16)), -(1 << 15));
+        assert_eq!(raw(&x.saturating_to(16)), (1 << 15) - 1);
+        assert_eq!(raw(&(-x.clone()).wrapping_to(16)), -(1 << 15));
+        assert_eq!(raw(&(-x.clone()).saturating_to(16)), -(1 << 15));
+        assert_eq!(raw(&q15(-70000).wrapping_to(16)), -70000 + 65536);
+        assert_eq!(raw(&q15(-70000).wrapping_to(32)), -70000);
+        assert_eq!(raw(&q15(12345).wrapping_to(16)), 12345);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(q15(3 << 13).to_string(), "0.75");
+        assert_eq!(q15(-(3 << 13)).to_string(), "-0.75");
+        assert_eq!(Q15::from(-2i64).to_string(), "-2");
+        assert_eq!(q15(1).to_string(), "0.000030517578125");
+        assert_eq!(format!("{:.2}", q15(1 << 14)), "0.50");
+        assert_eq!(format!("{:.3}", q15(1)), "0.000");
+        assert_eq!(format!("{:.3}", q15(-1)), "0.000");
+        assert_eq!(format!("{:.1}", q15(5 << 12)), "0.6");
+        assert_eq!(format!("{:.1}", q15(3 << 12)), "0.4");
+        assert_eq!(format!("{:>7}", q15(-(1 << 14))), "   -0.5");
+        assert_eq!(format!("{:+}", Fixed::<0>::from(7u64)), "+7");
+        assert_eq!(format!("{:.20}", q15(1)), "0.00003051757812500000");
+        assert_eq!(Fixed::<8>::from(0u64).to_string(), "0");
+        assert_eq!(format!("{:+}", Fixed::<8>::from(0u64)), "+0");
+        assert_eq!(format!("{:.2}", Fixed::<8>::from(0u64)), "0.00");
+        assert_eq!(format!("{:.10}", Q15::from(0u64)), "0.0000000000");
+        assert_eq!(Fixed::<0>::from(0u64).to_string(), "0");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("0.75".parse::<Q15>().unwrap(), q15(3 << 13));
+        assert_eq!("-1.5".parse::<Q15>().unwrap(), q15(-3 << 14));
+        assert_eq!("1/3".parse::<Q15>().unwrap(), q15(10923));
+        assert_eq!("0.1".parse::<Q15>().unwrap(), q15(3277));
+        assert_eq!("0.1".parse::<Fixed<4>>().unwrap().to_string(), "0.125");
+        // ties go to even
+        assert_eq!("0.5".parse::<Fixed<0>>().unwrap(), Fixed::from(0u64));
+        assert_eq!("1.5".parse::<Fixed<0>>().unwrap(), Fixed::from(2u64));
+        for s in ["", "x", "1/0", "1.2.3"] {
+            assert!(s.parse::<Q15>().is_err(), "{s:?}");
+        }
+        let q = Rational::new(true, UInt::from(1u64), UInt::from(3u64));
+        assert_eq!(
+            raw(&Q15::from_rational(&q, RoundingMode::TowardZero)),
+            -10922
+        );
+        assert_eq!(
+            raw(&Q15::from_rational(&q, RoundingMode::TowardNegative)),
+            -10923
+        );
+        for x in [q15(12345), q15(-1), Q15::from(100000u64)] {
+            assert_eq!(x.to_string().parse::<Q15>().unwrap(), x);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_zero() {
+        let _ = Q15::from(1u64) / Q15::from(0u64);
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod fixed_uint {
+    use binum::{Boolean, FixedUInt, UInt, U128, U16, U256, U8};
+    use Boolean::*;
+
+    const T: Boolean = True;
+    const F: Boolean = False;
+
+    const VALUES: [u8; 10] = [0, 1, 2, 3, 7, 0x80, 0x9b, 0xa0, 0xfe, 0xff];
+
+    fn u8_of(n: u8) -> U8 {
+        U8::wrapping_from(&UInt::from(n))
+    }
+
+    fn u128_of(n: u128) -> U128 {
+        U128::wrapping_from(&UInt::from(n))
+    }
+
+    #[test]
+    fn test_basics() {
+        assert_eq!(U8::BITS, 8);
+        assert_eq!(U8::ZERO, u8_of(0));
+        assert_eq!(U8::MAX, u8_of(u8::MAX));
+        assert_eq!(U8::default(), U8::ZERO);
+        assert_eq!(U8::new([T, T, F, T, F, F, F, F]), u8_of(0b1011));
+        assert_eq!(u8_of(0b1011).binary(), &[T, T, F, T, F, F, F, F]);
+        assert_eq!(UInt::from(u8_of(0x9b)), UInt::from(0x9bu64));
+        assert_eq!(UInt::from(u8_of(0x9b)).bit_len(), 8);
+    }
+
+    #[test]
+    fn test_from_uint() {
+        assert_eq!(U8::wrapping_from(&UInt::from(0x1ffu64)), U8::MAX);
+        assert!(U8::try_from(UInt::from(0x1ffu64)).is_err());
+        assert_eq!(U8::try_from(UInt::from(0xffu64)), Ok(U8::MAX));
+        assert_eq!(
+            U16::try_from(UInt::new([True, False, False])),
+            Ok(U16::wrapping_from(&UInt::from(1u64)))
+        );
+    }
+
+    #[test]
+    fn test_arith() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (u8_of(a), u8_of(b));
+                assert_eq!(x + y, u8_of(a.wrapping_add(b)));
+                assert_eq!(x - y, u8_of(a.wrapping_sub(b)));
+                assert_eq!(x * y, u8_of(a.wrapping_mul(b)));
+                assert_eq!(
+                    x.overflowing_add(y),
+                    (u8_of(a.wrapping_add(b)), a.overflowing_add(b).1)
+                );
+                assert_eq!(
+                    x.overflowing_sub(y),
+                    (u8_of(a.wrapping_sub(b)), a.overflowing_sub(b).1)
+                );
+                if let (Some(q), Some(r)) = (a.checked_div(b), a.checked_rem(b)) {
+                    assert_eq!(x / y, u8_of(q));
+                    assert_eq!(x % y, u8_of(r));
+                }
+                let mut n = x;
+                n += y;
+                n *= y;
+                n -= x;
+                assert_eq!(n, u8_of(a.wrapping_add(b).wrapping_mul(b).wrapping_sub(a)));
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitwise() {
+        for a in VALUES {
+            assert_eq!(!u8_of(a), u8_of(!a));
+            assert_eq!(u8_of(a).wrapping_neg(), u8_of(a.wrapping_neg()));
+            for b in VALUES {
+                let (x, y) = (u8_of(a), u8_of(b));
+                assert_eq!(x & y, u8_of(a & b));
+                assert_eq!(x | y, u8_of(a | b));
+                assert_eq!(x ^ y, u8_of(a ^ b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift_rotate() {
+        for a in VALUES {
+            for k in 0..8 {
+                assert_eq!(u8_of(a) << k, u8_of(a << k));
+                assert_eq!(u8_of(a) >> k, u8_of(a >> k));
+                assert_eq!(u8_of(a).rotate_left(k), u8_of(a.rotate_left(k as u32)));
+                assert_eq!(u8_of(a).rotate_right(k), u8_of(a.rotate_right(k as u32)));
+            }
+            assert_eq!(u8_of(a) << 8, U8::ZERO);
+            assert_eq!(u8_of(a) >> 100, U8::ZERO);
+            assert_eq!(u8_of(a).rotate_left(19), u8_of(a.rotate_left(19)));
+        }
+        let mut n = u8_of(0b1011);
+        n <<= 3;
+        n >>= 1;
+        assert_eq!(n, u8_of(0b101100));
+    }
+
+    #[test]
+    fn test_wide() {
+        let values = [
+            0,
+            1,
+            u64::MAX as u128,
+            0x1234_5678_9abc_def0_1234_5678_9abc_def0,
+            u128::MAX,
+        ];
+        for a in values {
+            for b in values {
+                let (x, y) = (u128_of(a), u128_of(b));
+                assert_eq!(x + y, u128_of(a.wrapping_add(b)));
+                assert_eq!(x - y, u128_of(a.wrapping_sub(b)));
+                assert_eq!(x * y, u128_of(a.wrapping_mul(b)));
+            }
+            assert_eq!(u128_of(a).rotate_left(77), u128_of(a.rotate_left(77)));
+        }
+        // 2^256 - 1 wraps to 0
+        assert_eq!(
+            U256::MAX + U256::wrapping_from(&UInt::from(1u64)),
+            U256::ZERO
+        );
+        let n = U256::wrapping_from(&(UInt::from(1u64) << 255usize));
+        assert_eq!(n * U256::wrapping_from(&UInt::from(2u64)), U256::ZERO);
+        assert_eq!(UInt::from(U256::MAX), UInt::new([True; 256]));
+        assert_eq!(
+            FixedUInt::<3>::MAX + FixedUInt::<3>::MAX,
+            FixedUInt::<3>::new([F, T, T])
+        );
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(u8_of(0x9b).to_string(), "155");
+        assert_eq!(format!("{:#x}", u8_of(0x9b)), "0x9b");
+        assert_eq!(format!("{:08b}", u8_of(5)), "00000101");
+    }
+}
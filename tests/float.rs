@@ -0,0 +1,357 @@
+mod common;
+
+#[cfg(test)]
+mod float {
+    use crate::common::XorShift;
+    use binum::{Float, Rational, RoundingMode, UInt, BF16, F16, F32, F64};
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestTiesToEven,
+        RoundingMode::NearestTiesToAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+    ];
+
+    // mostly random bit patterns, with special values mixed in
+    fn random_f64(rng: &mut XorShift) -> f64 {
+        const SPECIAL: [f64; 10] = [
+            0.0,
+            -0.0,
+            1.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -2.5e-310,
+        ];
+        let r = rng.next_u64();
+        match r % 8 {
+            0 => SPECIAL[(r >> 8) as usize % SPECIAL.len()],
+            // exponents near 1.0, so sums and products interact
+            1..=3 => f64::from_bits(r >> 12 | (1020 + (r >> 4) % 8) << 52),
+            _ => f64::from_bits(rng.next_u64()),
+        }
+    }
+
+    fn random_f32(rng: &mut XorShift) -> f32 {
+        let r = rng.next_u64();
+        match r % 8 {
+            0 => random_f64(rng) as f32,
+            1..=3 => f32::from_bits((r >> 41) as u32 | ((124 + (r >> 4) % 8) as u32) << 23),
+            _ => f32::from_bits(rng.next_u64() as u32),
+        }
+    }
+
+    fn same_f64(a: f64, b: f64) -> bool {
+        a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan()
+    }
+
+    fn same_f32(a: f32, b: f32) -> bool {
+        a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan()
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(f64::from(F64::ZERO).to_bits(), 0.0f64.to_bits());
+        assert_eq!(f64::from(F64::NEG_ZERO).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(f64::from(F64::INFINITY), f64::INFINITY);
+        assert_eq!(f64::from(F64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(f64::from(F64::MAX), f64::MAX);
+        assert!(f64::from(F64::NAN).is_nan());
+        assert_eq!(f32::from(F32::MAX), f32::MAX);
+        assert_eq!(F16::MAX.to_bits(), UInt::from(0x7bffu64));
+        assert_eq!(BF16::MAX.to_bits(), UInt::from(0x7f7fu64));
+        assert_eq!(F16::NAN.to_bits(), UInt::from(0x7e00u64));
+        assert_eq!(F32::default(), F32::ZERO);
+    }
+
+    #[test]
+    fn test_bits() {
+        let x = F32::from(-1.5f32);
+        assert!(x.is_sign_negative());
+        assert_eq!(x.to_bits(), UInt::from((-1.5f32).to_bits()));
+        assert_eq!(F32::from_bits(&x.to_bits()).to_bits(), x.to_bits());
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200 {
+            let x = random_f64(&mut rng);
+            assert_eq!(f64::from(F64::from(x)).to_bits(), x.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let mut rng = XorShift::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..500 {
+            let x = random_f64(&mut rng);
+            let y = F64::from(x);
+            assert_eq!(y.is_nan(), x.is_nan());
+            assert_eq!(y.is_infinite(), x.is_infinite());
+            assert_eq!(y.is_finite(), x.is_finite());
+            assert_eq!(y.is_zero(), x == 0.0);
+            assert_eq!(y.is_subnormal(), x.is_subnormal());
+            assert_eq!(y.is_sign_negative(), x.is_sign_negative());
+            assert!(same_f64(f64::from(-y), -x));
+            assert!(same_f64(f64::from(y.abs()), x.abs()));
+        }
+    }
+
+    #[test]
+    fn test_cmp() {
+        let mut rng = XorShift::new(0x1234_5678_9abc_def1);
+        for _ in 0..500 {
+            let (a, b) = (random_f64(&mut rng), random_f64(&mut rng));
+            let (x, y) = (F64::from(a), F64::from(b));
+            assert_eq!(x.partial_cmp(&y), a.partial_cmp(&b), "{a} {b}");
+            assert_eq!(x == y, a == b);
+        }
+        assert_eq!(F64::ZERO, F64::NEG_ZERO);
+        assert_ne!(F64::NAN, F64::NAN);
+    }
+
+    #[test]
+    fn test_f64_native() {
+        let mut rng = XorShift::new(0xdead_beef_cafe_f00d);
+        for _ in 0..1000 {
+            let (a, b) = (random_f64(&mut rng), random_f64(&mut rng));
+            let (x, y) = (F64::from(a), F64::from(b));
+            assert!(same_f64(f64::from(x + y), a + b), "{a:e} + {b:e}");
+            assert!(same_f64(f64::from(x - y), a - b), "{a:e} - {b:e}");
+            assert!(same_f64(f64::from(x * y), a * b), "{a:e} * {b:e}");
+            assert!(same_f64(f64::from(x / y), a / b), "{a:e} / {b:e}");
+            assert!(same_f64(f64::from(x.sqrt()), a.sqrt()), "sqrt({a:e})");
+        }
+    }
+
+    #[test]
+    fn test_f32_native() {
+        let mut rng = XorShift::new(0x0123_4567_89ab_cdef);
+        for _ in 0..1000 {
+            let (a, b) = (random_f32(&mut rng), random_f32(&mut rng));
+            let (x, y) = (F32::from(a), F32::from(b));
+            assert!(same_f32(f32::from(x + y), a + b), "{a:e} + {b:e}");
+            assert!(same_f32(f32::from(x - y), a - b), "{a:e} - {b:e}");
+            assert!(same_f32(f32::from(x * y), a * b), "{a:e} * {b:e}");
+            assert!(same_f32(f32::from(x / y), a / b), "{a:e} / {b:e}");
+            assert!(same_f32(f32::from(x.sqrt()), a.sqrt()), "sqrt({a:e})");
+            let mut z = x;
+            z += y;
+            z *= y;
+            z -= x;
+            z /= y;
+            assert!(same_f32(f32::from(z), ((a + b) * b - a) / b), "{a:e} {b:e}");
+            // rounding f64 to f32
+            let c = random_f64(&mut rng);
+            let converted = F64::from(c).convert::<8, 23>(RoundingMode::NearestTiesToEven);
+            assert!(same_f32(f32::from(converted), c as f32), "{c:e}");
+        }
+    }
+
+    // rounding an exact f64 result to a narrower format once is the same
+    // as rounding it twice, since 53 >= 2 * (MANT + 1) + 2
+    fn check_narrow<const EXP: usize, const MANT: usize>(seed: u64) {
+        let mode = RoundingMode::NearestTiesToEven;
+        let narrow = |a: f64| F64::from(a).convert::<EXP, MANT>(mode);
+        let mut rng = XorShift::new(seed);
+        for _ in 0..1000 {
+            let bits = 1 + EXP + MANT;
+            let x = Float::<EXP, MANT>::from_bits(&UInt::from(rng.next_u64() >> (64 - bits)));
+            let y = Float::<EXP, MANT>::from_bits(&UInt::from(rng.next_u64() >> (64 - bits)));
+            let a = f64::from(x.convert::<11, 52>(mode));
+            let b = f64::from(y.convert::<11, 52>(mode));
+            let cases = [
+                (x + y, narrow(a + b)),
+                (x - y, narrow(a - b)),
+                (x * y, narrow(a * b)),
+                (x / y, narrow(a / b)),
+                (x.sqrt(), narrow(a.sqrt())),
+            ];
+            for (got, expected) in cases {
+                assert!(
+                    got.to_bits() == expected.to_bits() || got.is_nan() && expected.is_nan(),
+                    "{a:e} {b:e}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_f16() {
+        check_narrow::<5, 10>(0x5555_aaaa_3333_cccc);
+        assert_eq!(F16::from_bits(&UInt::from(0x3c00u64)).to_string(), "1");
+        let tiny = F16::from_bits(&UInt::from(1u64));
+        assert!(tiny.is_subnormal());
+        assert_eq!(tiny.to_string(), "0.00000006");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut rng = XorShift::new(0x0123_4567_89ab_cdef);
+        for _ in 0..300 {
+            let x = random_f64(&mut rng);
+            assert_eq!(F64::from(x).to_string(), x.to_string(), "{x:e}");
+            assert_eq!(format!("{:.3}", F64::from(x)), format!("{x:.3}"));
+            let y = random_f32(&mut rng);
+            assert_eq!(F32::from(y).to_string(), y.to_string(), "{y:e}");
+        }
+        assert_eq!(format!("{:+}", F64::from(1.5)), "+1.5");
+        assert_eq!(format!("{:>6}", F64::from(-0.0)), "    -0");
+        assert_eq!(format!("{:.2}", F64::ZERO), "0.00");
+        assert_eq!(format!("{:.1}", F64::from(0.25)), "0.2");
+        assert_eq!(F16::MAX.to_string(), "65500");
+        assert_eq!(F64::NEG_INFINITY.to_string(), "-inf");
+        assert_eq!(F64::NAN.to_string(), "NaN");
+        // digits beyond f64 are kept in wider formats
+        type F128 = Float<15, 112>;
+        let tenth = F64::from(0.1).convert::<15, 112>(RoundingMode::NearestTiesToEven);
+        assert_eq!(tenth.to_string(), "0.1000000000000000055511151231257827");
+        let one = F128::from_bits(&(UInt::from(0x3fffu64) << 112usize));
+        let ten = F64::from(10.0).convert::<15, 112>(RoundingMode::NearestTiesToEven);
+        assert_eq!((one / ten).to_string(), "0.1");
+        let three = F64::from(3.0).convert::<15, 112>(RoundingMode::NearestTiesToEven);
+        assert_eq!((one / three).to_string(), format!("0.{}", "3".repeat(34)));
+    }
+
+    #[test]
+    fn test_bf16() {
+        check_narrow::<8, 7>(0x0f0f_f0f0_1234_4321);
+    }
+
+    fn next_up(x: f64) -> f64 {
+        if x == 0.0 {
+            f64::from_bits(1)
+        } else if x > 0.0 {
+            f64::from_bits(x.to_bits() + 1)
+        } else {
+            f64::from_bits(x.to_bits() - 1)
+        }
+    }
+
+    fn exact(x: f64) -> Rational {
+        Rational::from_f64(x).unwrap()
+    }
+
+    // checks every rounding mode of `f` against the exact value `q`
+    fn check_modes<F>(q: &Rational, f: F, what: &str)
+    where
+        F: Fn(RoundingMode) -> F64,
+    {
+        let results = MODES.map(|mode| f64::from(f(mode)));
+        let [even, away, zero, up, down] = results;
+        // the neighbors enclosing the exact value
+        assert!(exact(down) <= *q && *q <= exact(up), "{what}");
+        if exact(down) == *q {
+            assert!(results.iter().all(|&r| r == down), "{what}");
+            return;
+        }
+        assert_eq!(up, next_up(down), "{what}");
+        let toward_zero = if q.is_negative() { up } else { down };
+        assert_eq!(zero.to_bits(), toward_zero.to_bits(), "{what}");
+        let d_down = q.clone() - exact(down);
+        let d_up = exact(up) - q.clone();
+        let (nearest_even, nearest_away) = match d_down.cmp(&d_up) {
+            std::cmp::Ordering::Less => (down, down),
+            std::cmp::Ordering::Greater => (up, up),
+            std::cmp::Ordering::Equal => {
+                let even = if down.to_bits() % 2 == 0 { down } else { up };
+                let away = if q.is_negative() { down } else { up };
+                (even, away)
+            }
+        };
+        assert_eq!(even.to_bits(), nearest_even.to_bits(), "{what}");
+        assert_eq!(away.to_bits(), nearest_away.to_bits(), "{what}");
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let mut rng = XorShift::new(0xfeed_face_0bad_f00d);
+        for _ in 0..300 {
+            // moderate exponents, so results stay finite and nonzero
+            let a = f64::from_bits(rng.next_u64() >> 12 | (960 + rng.next_u64() % 128) << 52);
+            let b = f64::from_bits(rng.next_u64() >> 12 | (960 + rng.next_u64() % 128) << 52);
+            let a = if rng.next_u64().is_multiple_of(2) {
+                a
+            } else {
+                -a
+            };
+            let (x, y) = (F64::from(a), F64::from(b));
+            let (qa, qb) = (exact(a), exact(b));
+            check_modes(&(qa.clone() + qb.clone()), |m| x.add_with(y, m), "add");
+            check_modes(&(qa.clone() - qb.clone()), |m| x.sub_with(y, m), "sub");
+            check_modes(&(qa.clone() * qb.clone()), |m| x.mul_with(y, m), "mul");
+            check_modes(&(qa / qb), |m| x.div_with(y, m), "div");
+            // the root r satisfies down^2 <= b <= up^2
+            let down = f64::from(y.sqrt_with(RoundingMode::TowardNegative));
+            let up = f64::from(y.sqrt_with(RoundingMode::TowardPositive));
+            let (down, up) = (exact(down), exact(up));
+            assert!(down.clone() * down.clone() <= exact(b));
+            assert!(exact(b) <= up.clone() * up.clone());
+            assert_eq!(
+                y.sqrt_with(RoundingMode::TowardZero).to_bits(),
+                y.sqrt_with(RoundingMode::TowardNegative).to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn test_ties() {
+        let one = F64::from(1.0);
+        let half_ulp = F64::from(2f64.powi(-53));
+        let sum = |x: F64, mode| f64::from(x.add_with(half_ulp, mode));
+        assert_eq!(sum(one, RoundingMode::NearestTiesToEven), 1.0);
+        assert_eq!(sum(one, RoundingMode::NearestTiesToAway), next_up(1.0));
+        let odd = F64::from(next_up(1.0));
+        let odd_up = next_up(next_up(1.0));
+        assert_eq!(sum(odd, RoundingMode::NearestTiesToEven), odd_up);
+        assert_eq!(sum(odd, RoundingMode::NearestTiesToAway), odd_up);
+        // -1 - 2^-53 lies halfway between -1 and its neighbor below
+        let diff = |mode| f64::from((-one).sub_with(half_ulp, mode));
+        assert_eq!(diff(RoundingMode::NearestTiesToEven), -1.0);
+        assert_eq!(diff(RoundingMode::NearestTiesToAway), -next_up(1.0));
+    }
+
+    #[test]
+    fn test_overflow_underflow() {
+        let max = F64::MAX;
+        let (inf, max_f64) = (f64::INFINITY, f64::MAX);
+        let positive = [inf, inf, max_f64, inf, max_f64];
+        let negative = [-inf, -inf, -max_f64, -max_f64, -inf];
+        for (mode, (pos, neg)) in MODES.into_iter().zip(positive.into_iter().zip(negative)) {
+            assert_eq!(f64::from(max.mul_with(F64::from(2.0), mode)), pos);
+            assert_eq!(f64::from((-max).mul_with(F64::from(2.0), mode)), neg);
+        }
+        let tiny = F64::from(5e-324);
+        let half = F64::from(0.5);
+        let expected = [0.0, 5e-324, 0.0, 5e-324, 0.0];
+        for (mode, expected) in MODES.into_iter().zip(expected) {
+            assert_eq!(f64::from(tiny.mul_with(half, mode)), expected);
+        }
+        // an exact zero sum is negative only when rounding down
+        let x = F64::from(1.5);
+        assert!(!x.sub_with(x, RoundingMode::TowardZero).is_sign_negative());
+        assert!(x
+            .sub_with(x, RoundingMode::TowardNegative)
+            .is_sign_negative());
+        assert!(F64::NEG_ZERO
+            .add_with(F64::NEG_ZERO, RoundingMode::NearestTiesToEven)
+            .is_sign_negative());
+    }
+
+    #[test]
+    fn test_special() {
+        let nan = F64::NAN;
+        let inf = F64::INFINITY;
+        assert!((inf - inf).is_nan());
+        assert!((inf * F64::ZERO).is_nan());
+        assert!((F64::ZERO / F64::ZERO).is_nan());
+        assert!((inf / inf).is_nan());
+        assert!(F64::from(-1.0).sqrt().is_nan());
+        assert!((nan + F64::from(1.0)).is_nan());
+        assert_eq!(F64::from(1.0) / F64::ZERO, inf);
+        assert_eq!(F64::from(-1.0) / F64::ZERO, F64::NEG_INFINITY);
+        assert!(F64::NEG_ZERO.sqrt().is_sign_negative());
+        assert_eq!(inf.sqrt(), inf);
+    }
+}
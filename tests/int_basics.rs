@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod int_basics {
+    use binum::{Int, UInt};
+
+    #[test]
+    fn test_new() {
+        let n = Int::new(true, UInt::from(5u64));
+        assert!(n.is_negative());
+        assert_eq!(n.magnitude(), &UInt::from(5u64));
+        // zero is never negative
+        let n = Int::new(true, UInt::new([]));
+        assert!(!n.is_negative());
+        assert!(n.is_zero());
+        assert_eq!(n, Int::from(0));
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Int::from(-7), Int::new(true, UInt::from(7u64)));
+        assert_eq!(Int::from(7), Int::new(false, UInt::from(7u64)));
+        assert_eq!(Int::from(UInt::from(7u64)), Int::from(7));
+        let n = Int::from(i64::MIN);
+        assert!(n.is_negative());
+        assert_eq!(n.unsigned_abs(), UInt::from(1u64 << 63));
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(Int::from(-3).signum(), Int::from(-1));
+        assert_eq!(Int::from(0).signum(), Int::from(0));
+        assert_eq!(Int::from(3).signum(), Int::from(1));
+        assert_eq!(Int::from(-3).abs(), Int::from(3));
+        assert!(Int::from(3).is_positive());
+        assert!(!Int::from(0).is_positive());
+        assert_eq!(Int::from(-3).into_parts(), (true, UInt::from(3u64)));
+    }
+
+    #[test]
+    fn test_ord() {
+        let values = [-300, -17, -1, 0, 1, 2, 17, 300];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                assert_eq!(Int::from(a).cmp(&Int::from(b)), a.cmp(&b));
+                assert_eq!(Int::from(a) == Int::from(b), a == b);
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod int_ops {
+    use binum::{Int, UInt};
+
+    const VALUES: [i64; 13] = [-256, -255, -100, -17, -8, -2, -1, 0, 1, 2, 9, 64, 255];
+
+    #[test]
+    fn test_neg() {
+        for &a in VALUES.iter() {
+            assert_eq!(-Int::from(a), Int::from(-a));
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        for &a in VALUES.iter() {
+            for &b in VALUES.iter() {
+                assert_eq!(Int::from(a) + Int::from(b), Int::from(a + b));
+                assert_eq!(Int::from(a) - Int::from(b), Int::from(a - b));
+                let mut n = Int::from(a);
+                n += Int::from(b);
+                assert_eq!(n, Int::from(a + b));
+                let mut n = Int::from(a);
+                n -= Int::from(b);
+                assert_eq!(n, Int::from(a - b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        for &a in VALUES.iter() {
+            for &b in VALUES.iter() {
+                assert_eq!(Int::from(a) * Int::from(b), Int::from(a * b));
+                let mut n = Int::from(a);
+                n *= Int::from(b);
+                assert_eq!(n, Int::from(a * b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        for &a in VALUES.iter() {
+            for &b in VALUES.iter().filter(|&&b| b != 0) {
+                assert_eq!(Int::from(a) / Int::from(b), Int::from(a / b));
+                assert_eq!(Int::from(a) % Int::from(b), Int::from(a % b));
+                let mut n = Int::from(a);
+                n /= Int::from(b);
+                assert_eq!(n, Int::from(a / b));
+                let mut n = Int::from(a);
+                n %= Int::from(b);
+                assert_eq!(n, Int::from(a % b));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_fails() {
+        let _ = Int::from(-1) / Int::from(0);
+    }
+
+    #[test]
+    fn test_bitwise() {
+        for &a in VALUES.iter() {
+            assert_eq!(!Int::from(a), Int::from(!a));
+            for &b in VALUES.iter() {
+                assert_eq!(Int::from(a) & Int::from(b), Int::from(a & b));
+                assert_eq!(Int::from(a) | Int::from(b), Int::from(a | b));
+                assert_eq!(Int::from(a) ^ Int::from(b), Int::from(a ^ b));
+                let mut n = Int::from(a);
+                n &= Int::from(b);
+                assert_eq!(n, Int::from(a & b));
+                let mut n = Int::from(a);
+                n |= Int::from(b);
+                assert_eq!(n, Int::from(a | b));
+                let mut n = Int::from(a);
+                n ^= Int::from(b);
+                assert_eq!(n, Int::from(a ^ b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift() {
+        for &a in VALUES.iter() {
+            for k in 0..10u32 {
+                assert_eq!(Int::from(a) << UInt::from(k), Int::from(a << k));
+                assert_eq!(Int::from(a) >> UInt::from(k), Int::from(a >> k));
+                let mut n = Int::from(a);
+                n <<= UInt::from(k);
+                assert_eq!(n, Int::from(a << k));
+                let mut n = Int::from(a);
+                n >>= UInt::from(k);
+                assert_eq!(n, Int::from(a >> k));
+            }
+        }
+    }
+}
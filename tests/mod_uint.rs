@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod mod_uint {
+    use std::rc::Rc;
+
+    use binum::{ModUInt, UInt};
+
+    fn residues(m: u64) -> (Rc<UInt>, Vec<ModUInt>) {
+        let modulus = Rc::new(UInt::from(m));
+        let values = (0..m)
+            .map(|v| ModUInt::new(UInt::from(v), modulus.clone()))
+            .collect();
+        (modulus, values)
+    }
+
+    #[test]
+    fn test_new() {
+        let m = Rc::new(UInt::from(7u64));
+        let a = ModUInt::new(UInt::from(30u64), m.clone());
+        assert_eq!(a.value(), &UInt::from(2u64));
+        assert_eq!(a.modulus(), &m);
+        assert_eq!(a.to_string(), "2");
+        assert_eq!(a.clone().into_value(), UInt::from(2u64));
+        assert_eq!(a, ModUInt::new(UInt::from(9u64), Rc::new(UInt::from(7u64))));
+        assert_ne!(a, ModUInt::new(UInt::from(2u64), Rc::new(UInt::from(8u64))));
+    }
+
+    #[test]
+    fn test_ops() {
+        let m = 13u64;
+        let (_, values) = residues(m);
+        for (a, x) in values.iter().zip(0u64..) {
+            assert_eq!(-a.clone(), values[((m - x) % m) as usize]);
+            for (b, y) in values.iter().zip(0u64..) {
+                assert_eq!(a.clone() + b.clone(), values[((x + y) % m) as usize]);
+                assert_eq!(a.clone() - b.clone(), values[((x + m - y) % m) as usize]);
+                assert_eq!(a.clone() * b.clone(), values[(x * y % m) as usize]);
+                if y != 0 {
+                    assert_eq!((a.clone() / b.clone()) * b.clone(), a.clone());
+                }
+                let mut c = a.clone();
+                c += b.clone();
+                c -= b.clone();
+                c *= b.clone();
+                assert_eq!(c, values[(x * y % m) as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_inverse() {
+        let p = Rc::new((UInt::from(1u64) << 127usize) - 1u64);
+        let a = ModUInt::new(UInt::from(123_456_789u64), p.clone());
+        let one = ModUInt::new(UInt::from(1u64), p.clone());
+        assert_eq!(a.pow(&(&*p - 1u64)), one);
+        assert_eq!(a.clone() * a.inverse().unwrap(), one);
+        let mut b = one.clone();
+        b /= a.clone();
+        assert_eq!(b, a.inverse().unwrap());
+
+        let m = Rc::new(UInt::from(12u64));
+        assert!(ModUInt::new(UInt::from(4u64), m.clone())
+            .inverse()
+            .is_none());
+        let five = ModUInt::new(UInt::from(5u64), m);
+        assert_eq!(five.inverse(), Some(five.clone()));
+        assert_eq!(five.pow(&UInt::from(0u64)).value(), &UInt::from(1u64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_fails() {
+        let m = Rc::new(UInt::from(12u64));
+        let _ = ModUInt::new(UInt::from(1u64), m.clone()) / ModUInt::new(UInt::from(3u64), m);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_moduli() {
+        let a = ModUInt::new(UInt::from(1u64), Rc::new(UInt::from(5u64)));
+        let b = ModUInt::new(UInt::from(1u64), Rc::new(UInt::from(7u64)));
+        let _ = a + b;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_modulus() {
+        let _ = ModUInt::new(UInt::from(1u64), Rc::new(UInt::from(0u64)));
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod num_theory {
+    use binum::num_theory::{crt, extended_gcd, gcd, lcm, mod_inverse, CrtError};
+    use binum::{Int, UInt};
+
+    fn naive_gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            naive_gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn test_gcd() {
+        for a in 0..60u64 {
+            for b in 0..60u64 {
+                let g = gcd(&UInt::from(a), &UInt::from(b));
+                assert_eq!(g, UInt::from(naive_gcd(a, b)), "gcd({a}, {b})");
+            }
+        }
+        let p = UInt::from(3u64).pow(150);
+        let q = UInt::from(6u64).pow(100);
+        assert_eq!(gcd(&p, &q), UInt::from(3u64).pow(100));
+        let a = (UInt::from(1u64) << 300usize) * 12345u64;
+        let b = (UInt::from(1u64) << 200usize) * 54321u64;
+        assert_eq!(gcd(&a, &b), (UInt::from(1u64) << 200usize) * 3u64);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let cases = [
+            (0u64, 0u64),
+            (0, 5),
+            (5, 0),
+            (240, 46),
+            (46, 240),
+            (17, 5),
+            (u64::MAX, 1 << 40),
+        ];
+        for (a, b) in cases {
+            let (g, x, y) = extended_gcd(&UInt::from(a), &UInt::from(b));
+            assert_eq!(g, UInt::from(naive_gcd(a, b)));
+            let lhs = Int::from(a) * x + Int::from(b) * y;
+            assert_eq!(lhs, Int::from(g));
+        }
+        let a = UInt::from(7u64).pow(80);
+        let b = UInt::from(11u64).pow(70) * 49u64;
+        let (g, x, y) = extended_gcd(&a, &b);
+        assert_eq!(g, UInt::from(49u64));
+        assert_eq!(Int::from(a) * x + Int::from(b) * y, Int::from(g));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(&UInt::from(4u64), &UInt::from(6u64)), UInt::from(12u64));
+        assert_eq!(lcm(&UInt::from(0u64), &UInt::from(6u64)), UInt::from(0u64));
+        assert_eq!(lcm(&UInt::from(7u64), &UInt::from(7u64)), UInt::from(7u64));
+        let a = UInt::from(2u64).pow(100);
+        let b = UInt::from(3u64).pow(100);
+        assert_eq!(lcm(&a, &b), UInt::from(6u64).pow(100));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        for m in 1..50u64 {
+            for a in 0..60u64 {
+                let inv = mod_inverse(&UInt::from(a), &UInt::from(m));
+                if naive_gcd(a, m) == 1 {
+                    let inv = u64::try_from(inv.unwrap()).unwrap();
+                    assert!(inv < m);
+                    assert_eq!(a * inv % m, 1 % m, "{a}^-1 mod {m}");
+                } else {
+                    assert!(inv.is_none(), "{a}^-1 mod {m}");
+                }
+            }
+        }
+        assert!(mod_inverse(&UInt::from(3u64), &UInt::from(0u64)).is_none());
+        let p = (UInt::from(1u64) << 127usize) - 1u64;
+        let a = UInt::from(123_456_789u64);
+        let inv = mod_inverse(&a, &p).unwrap();
+        assert_eq!(a * inv % &p, UInt::from(1u64));
+    }
+
+    fn congruences(pairs: &[(u64, u64)]) -> Vec<(UInt, UInt)> {
+        pairs
+            .iter()
+            .map(|&(a, n)| (UInt::from(a), UInt::from(n)))
+            .collect()
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[]), Ok((UInt::from(0u64), UInt::from(1u64))));
+        let (x, m) = crt(&congruences(&[(2, 3), (3, 5), (2, 7)])).unwrap();
+        assert_eq!((x, m), (UInt::from(23u64), UInt::from(105u64)));
+        // residues above the modulus are reduced
+        let (x, m) = crt(&congruences(&[(17, 4), (100, 9)])).unwrap();
+        assert_eq!((x, m), (UInt::from(1u64), UInt::from(36u64)));
+
+        // brute force over small, possibly non-coprime moduli
+        for n1 in 1..13u64 {
+            for n2 in 1..13u64 {
+                for a1 in 0..n1 {
+                    for a2 in 0..n2 {
+                        let l = n1 * n2 / naive_gcd(n1, n2);
+                        let expected = (0..l).find(|x| x % n1 == a1 && x % n2 == a2);
+                        let res = crt(&congruences(&[(a1, n1), (a2, n2)]));
+                        match expected {
+                            Some(x) => assert_eq!(res, Ok((UInt::from(x), UInt::from(l)))),
+                            None => assert_eq!(res, Err(CrtError::Inconsistent { index: 1 })),
+                        }
+                    }
+                }
+            }
+        }
+
+        let p = (UInt::from(1u64) << 61usize) - 1u64;
+        let q = (UInt::from(1u64) << 89usize) - 1u64;
+        let x = UInt::from(3u64).pow(90);
+        let (y, m) = crt(&[(&x % &p, p.clone()), (&x % &q, q.clone())]).unwrap();
+        assert_eq!(m, &p * &q);
+        assert_eq!(y, &x % &m);
+    }
+
+    #[test]
+    fn test_crt_errors() {
+        let err = crt(&congruences(&[(1, 4), (2, 0)])).unwrap_err();
+        assert_eq!(err, CrtError::ZeroModulus { index: 1 });
+        assert_eq!(err.to_string(), "modulus of congruence 1 is zero");
+        let err = crt(&congruences(&[(1, 4), (1, 3), (2, 6)])).unwrap_err();
+        assert_eq!(err, CrtError::Inconsistent { index: 2 });
+        assert_eq!(
+            err.to_string(),
+            "congruence 2 is inconsistent with the preceding congruences"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+mod common;
+
+#[cfg(test)]
+mod rational {
+    use crate::common::XorShift;
+    use binum::{Int, Rational, RationalErrorKind, UInt};
+
+    fn r(n: i64, d: u64) -> Rational {
+        Rational::new(n < 0, UInt::from(n.unsigned_abs()), UInt::from(d))
+    }
+
+    #[test]
+    fn test_new() {
+        let a = r(-6, 8);
+        assert!(a.is_negative());
+        assert_eq!(a.numer(), &UInt::from(3u64));
+        assert_eq!(a.denom(), &UInt::from(4u64));
+        assert_eq!(r(0, 5), Rational::from(0u8));
+        assert!(!Rational::new(true, UInt::from(0u64), UInt::from(3u64)).is_negative());
+        assert!(r(10, 5).is_integer());
+        assert_eq!(r(-3, 4).abs(), r(3, 4));
+        assert_eq!(r(-3, 4).recip(), r(-4, 3));
+        assert_eq!(Rational::from(Int::from(-7)), r(-7, 1));
+        assert_eq!(Rational::from(UInt::from(7u64)), r(7, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_denominator() {
+        let _ = r(1, 0);
+    }
+
+    #[test]
+    fn test_ops() {
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) + r(-1, 2), r(0, 1));
+        assert_eq!(r(1, 3) - r(1, 2), r(-1, 6));
+        assert_eq!(r(-2, 3) * r(9, 4), r(-3, 2));
+        assert_eq!(r(-2, 3) / r(-4, 9), r(3, 2));
+        assert_eq!(-r(2, 3), r(-2, 3));
+        assert_eq!(-r(0, 3), r(0, 3));
+        let mut a = r(1, 6);
+        a += r(1, 3);
+        assert_eq!(a, r(1, 2));
+        a -= r(1, 1);
+        assert_eq!(a, r(-1, 2));
+        a *= r(4, 1);
+        assert_eq!(a, r(-2, 1));
+        a /= r(-8, 3);
+        assert_eq!(a, r(3, 4));
+        // probability that six dice all differ
+        let p = (1..6u64).fold(r(1, 1), |acc, k| acc * r(6 - k as i64, 6));
+        assert_eq!(p, r(5, 324));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_zero() {
+        let _ = r(1, 2) / r(0, 1);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut values = vec![r(1, 2), r(-1, 3), r(2, 3), r(0, 1), r(-1, 2), r(1, 3)];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![r(-1, 2), r(-1, 3), r(0, 1), r(1, 3), r(1, 2), r(2, 3)]
+        );
+        assert!(r(1, 3) < r(334, 1000));
+        assert!(r(-1, 3) > r(-334, 1000));
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(r(-6, 8).to_string(), "-3/4");
+        assert_eq!(r(5, 1).to_string(), "5/1");
+        assert_eq!(format!("{:>6}", r(1, 2)), "   1/2");
+        assert_eq!(format!("{:+}", r(1, 2)), "+1/2");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3/4".parse::<Rational>(), Ok(r(3, 4)));
+        assert_eq!("-6/8".parse::<Rational>(), Ok(r(-3, 4)));
+        assert_eq!("+6/8".parse::<Rational>(), Ok(r(3, 4)));
+        assert_eq!("0.125".parse::<Rational>(), Ok(r(1, 8)));
+        assert_eq!("-1.5".parse::<Rational>(), Ok(r(-3, 2)));
+        assert_eq!(".5".parse::<Rational>(), Ok(r(1, 2)));
+        assert_eq!("2.".parse::<Rational>(), Ok(r(2, 1)));
+        assert_eq!("42".parse::<Rational>(), Ok(r(42, 1)));
+        let cases = [
+            ("", RationalErrorKind::Empty),
+            ("-", RationalErrorKind::InvalidDigit),
+            (".", RationalErrorKind::InvalidDigit),
+            ("1/", RationalErrorKind::InvalidDigit),
+            ("1/-2", RationalErrorKind::InvalidDigit),
+            ("1.2.3", RationalErrorKind::InvalidDigit),
+            ("0x10", RationalErrorKind::InvalidDigit),
+            ("1/0", RationalErrorKind::ZeroDenominator),
+        ];
+        for (s, kind) in cases {
+            assert_eq!(s.parse::<Rational>().unwrap_err().kind(), &kind, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(Rational::from_f64(0.375), Some(r(3, 8)));
+        assert_eq!(Rational::from_f64(-2.0), Some(r(-2, 1)));
+        assert_eq!(Rational::from_f64(-0.0), Some(r(0, 1)));
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        assert_eq!(Rational::from_f64(f64::INFINITY), None);
+        let tiny = Rational::from_f64(f64::from_bits(1)).unwrap();
+        assert_eq!(tiny.denom(), &(UInt::from(1u64) << 1074usize));
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(r(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(r(-2, 3).to_f64(), -2.0 / 3.0);
+        assert_eq!(r(1, 10).to_f64(), 0.1);
+        assert_eq!(r(0, 1).to_f64(), 0.0);
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        let mut next = || rng.next_u64();
+        for _ in 0..1000 {
+            let a = next() >> 11;
+            let b = (next() >> 11).max(1);
+            let expected = a as f64 / b as f64;
+            assert_eq!(r(a as i64, b).to_f64(), expected, "{a}/{b}");
+            let x = f64::from_bits(next());
+            if let Some(q) = Rational::from_f64(x) {
+                assert_eq!(
+                    q.to_f64().to_bits(),
+                    x.to_bits() & !(u64::from(x == 0.0) << 63)
+                );
+            }
+        }
+        let one = UInt::from(1u64);
+        let big = Rational::from(&one << 1024usize);
+        assert_eq!(big.to_f64(), f64::INFINITY);
+        assert_eq!((-big).to_f64(), f64::NEG_INFINITY);
+        let pow = |k: usize| Rational::new(false, one.clone(), &one << k);
+        assert_eq!(pow(1074).to_f64(), f64::from_bits(1));
+        // ties to even
+        assert_eq!(pow(1075).to_f64(), 0.0);
+        assert_eq!((pow(1075) * r(3, 1)).to_f64(), f64::from_bits(2));
+        assert_eq!((pow(1076) * r(3, 1)).to_f64(), f64::from_bits(1));
+        let max = Rational::from_f64(f64::MAX).unwrap();
+        assert_eq!(max.to_f64(), f64::MAX);
+    }
+}
@@ -11,27 +11,27 @@ mod uint_basics {
         // u64 -> UInt
         let nu64: u64 = 0;
         let nuint: UInt = nu64.into();
-        assert_eq!(nuint.binary(), &vec![]);
+        assert_eq!(nuint.binary(), vec![]);
         let nu64: u64 = 0b10011011;
         let nuint: UInt = nu64.into();
         assert_eq!(
             nuint.binary(),
-            &("10011011"
+            "10011011"
                 .chars()
                 .rev()
                 .map(|c| Boolean::from(c == '1'))
-                .collect::<Vec<_>>())
+                .collect::<Vec<_>>()
         );
         let nu64 = u64::MAX;
         let nuint: UInt = nu64.into();
-        assert_eq!(nuint.binary(), &vec![True; u64::BITS as usize]);
+        assert_eq!(nuint.binary(), vec![True; u64::BITS as usize]);
     }
 
     #[test]
     fn test_uint_to_u64() {
         // UInt -> u64
         let nuint = UInt::new([]);
-        let nu64: u64 = nuint.into();
+        let nu64: u64 = nuint.try_into().unwrap();
         assert_eq!(nu64, 0);
         let nuint = UInt::new(
             "10011011"
@@ -40,10 +40,10 @@ mod uint_basics {
                 .map(|c| Boolean::from(c == '1'))
                 .collect::<Vec<_>>(),
         );
-        let nu64: u64 = nuint.into();
+        let nu64: u64 = nuint.try_into().unwrap();
         assert_eq!(nu64, 0b10011011);
         let nuint = UInt::new([True; u64::BITS as usize]);
-        let nu64: u64 = nuint.into();
+        let nu64: u64 = nuint.try_into().unwrap();
         assert_eq!(nu64, u64::MAX);
     }
 
@@ -70,11 +70,11 @@ mod uint_basics {
     #[test]
     fn test_trim() {
         let n = UInt::new([]);
-        assert_eq!(n.trim().binary(), &vec![]);
+        assert_eq!(n.trim().binary(), vec![]);
         let n = UInt::new([False; 8]);
-        assert_eq!(n.trim().binary(), &vec![]);
+        assert_eq!(n.trim().binary(), vec![]);
         let n = UInt::new([True; 8]);
-        assert_eq!(n.trim().binary(), &vec![True; 8]);
+        assert_eq!(n.trim().binary(), vec![True; 8]);
         let n = UInt::new(
             "00011011"
                 .chars()
@@ -82,22 +82,22 @@ mod uint_basics {
                 .map(|c| Boolean::from(c == '1'))
                 .collect::<Vec<_>>(),
         );
-        assert_eq!(n.trim().binary(), &vec![T, T, F, T, T]);
+        assert_eq!(n.trim().binary(), vec![T, T, F, T, T]);
         let n = UInt::new([True; u64::BITS as usize]);
-        assert_eq!(n.trim().binary(), &vec![True; u64::BITS as usize]);
+        assert_eq!(n.trim().binary(), vec![True; u64::BITS as usize]);
     }
 
     #[test]
     fn test_trim_mut() {
         let mut n = UInt::new([]);
         n.trim_mut();
-        assert_eq!(n.binary(), &vec![]);
+        assert_eq!(n.binary(), vec![]);
         let mut n = UInt::new([False; 8]);
         n.trim_mut();
-        assert_eq!(n.binary(), &vec![]);
+        assert_eq!(n.binary(), vec![]);
         let mut n = UInt::new([True; 8]);
         n.trim_mut();
-        assert_eq!(n.binary(), &vec![True; 8]);
+        assert_eq!(n.binary(), vec![True; 8]);
         let mut n = UInt::new(
             "00011011"
                 .chars()
@@ -106,9 +106,61 @@ mod uint_basics {
                 .collect::<Vec<_>>(),
         );
         n.trim_mut();
-        assert_eq!(n.binary(), &vec![T, T, F, T, T]);
+        assert_eq!(n.binary(), vec![T, T, F, T, T]);
         let mut n = UInt::new([True; u64::BITS as usize]);
         n.trim_mut();
-        assert_eq!(n.binary(), &vec![True; u64::BITS as usize]);
+        assert_eq!(n.binary(), vec![True; u64::BITS as usize]);
+    }
+
+    #[test]
+    fn test_bits() {
+        let bin: Vec<_> = "0001101100000000000000000000000000000000000000000000000000000000111"
+            .chars()
+            .rev()
+            .map(|c| Boolean::from(c == '1'))
+            .collect();
+        let n = UInt::new(bin.clone());
+        assert_eq!(n.bit_len(), bin.len());
+        assert_eq!(n.bits().len(), bin.len());
+        assert_eq!(n.bits().collect::<Vec<_>>(), bin);
+        assert_eq!(n.bits().next_back(), Some(F));
+        assert_eq!(n.bits().next(), Some(T));
+        assert_eq!(n.binary(), bin);
+        assert_eq!(n.max_bit_digit(), 64);
+    }
+
+    #[test]
+    fn test_limbs() {
+        let n = UInt::new([True; 65]);
+        assert_eq!(n.limbs(), &[u64::MAX, 1]);
+        let n = UInt::from_limbs([0, 0, 5]);
+        assert_eq!(n.bit_len(), 192);
+        assert_eq!(n.max_bit_digit(), 131);
+        assert_eq!(n, UInt::from(5u64) << UInt::from(128u64));
+        let n = UInt::from_limbs([7, 0, 0]);
+        assert_eq!(n, UInt::from(7u64));
+        assert_eq!(n.trim().limbs(), &[7]);
+        assert_eq!(UInt::from(0u64).limbs(), &[] as &[u64]);
+    }
+
+    #[test]
+    fn test_multi_limb() {
+        // carries and shifts across limb boundaries
+        let n = UInt::from(u128::MAX);
+        let one = UInt::from(1u64);
+        let m = n.clone() + one.clone();
+        assert_eq!(m.max_bit_digit(), 129);
+        assert_eq!(m.clone() >> UInt::from(128u64), one);
+        assert_eq!(m.clone() >> UInt::from(129u64), UInt::from(0u64));
+        assert_eq!(one << UInt::from(128u64), m);
+        let n = UInt::from(0xdead_beef_u64) << UInt::from(100u64);
+        assert_eq!(n.clone() >> UInt::from(100u64), UInt::from(0xdead_beef_u64));
+        assert_eq!(n.clone() >> UInt::from(104u64), UInt::from(0x0dea_dbee_u64));
+        assert_eq!(
+            UInt::from(u64::MAX) * UInt::from(u64::MAX),
+            UInt::from(u64::MAX as u128 * u64::MAX as u128)
+        );
+        assert!(n > UInt::from(u128::MAX));
+        assert!(UInt::from(u128::MAX) < n);
     }
 }
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod uint_bits {
+    use binum::{Boolean, UInt};
+    use Boolean::*;
+
+    #[test]
+    fn test_bit() {
+        let n = UInt::from(0b1010u64);
+        assert_eq!(n.bit(0), False);
+        assert_eq!(n.bit(1), True);
+        assert_eq!(n.bit(3), True);
+        // past the end reads as False
+        assert_eq!(n.bit(64), False);
+        assert_eq!(n.bit(1000), False);
+        assert_eq!(UInt::new([]).bit(0), False);
+    }
+
+    #[test]
+    fn test_set_bit() {
+        let mut n = UInt::from(0b1010u64);
+        n.set_bit(0, True);
+        assert_eq!(n, UInt::from(0b1011u64));
+        n.set_bit(1, False);
+        assert_eq!(n, UInt::from(0b1001u64));
+        // writes past the end grow the storage
+        let mut n = UInt::new([]);
+        n.set_bit(130, True);
+        assert_eq!(n, UInt::from(1u64) << 130usize);
+        assert_eq!(n.bit_len(), 131);
+        n.set_bit(200, False);
+        assert_eq!(n.bit_len(), 201);
+        assert_eq!(n, UInt::from(1u64) << 130usize);
+        n.clear_bit(130);
+        assert_eq!(n, UInt::from(0u64));
+        n.clear_bit(5000);
+        assert_eq!(n.bit_len(), 5001);
+    }
+
+    #[test]
+    fn test_toggle_bit() {
+        let mut n = UInt::from(0b0110u64);
+        n.toggle_bit(1);
+        n.toggle_bit(3);
+        assert_eq!(n, UInt::from(0b1100u64));
+        n.toggle_bit(64);
+        assert_eq!(n, UInt::from(0b1100u128 | 1 << 64));
+        n.toggle_bit(64);
+        assert_eq!(n, UInt::from(0b1100u64));
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(UInt::new([]).count_ones(), 0);
+        assert_eq!(UInt::from(0b1011u64).count_ones(), 3);
+        assert_eq!(UInt::from(u128::MAX).count_ones(), 128);
+        assert_eq!(
+            UInt::new([True, False, False, True, False]).count_zeros(),
+            3
+        );
+        assert_eq!(UInt::from(0b1011u8).count_zeros(), 1);
+        assert_eq!(UInt::from(u64::MAX).count_zeros(), 0);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        assert_eq!(UInt::from(1u64).leading_zeros(8), 7);
+        assert_eq!(UInt::from(0u64).leading_zeros(8), 8);
+        assert_eq!(UInt::from(0xffu64).leading_zeros(8), 0);
+        for x in [1u64, 0x1234, u64::MAX >> 3] {
+            assert_eq!(UInt::from(x).leading_zeros(64), x.leading_zeros() as usize);
+        }
+        assert_eq!((UInt::from(1u64) << 100usize).leading_zeros(256), 155);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_leading_zeros_narrow() {
+        let _ = UInt::from(0x100u64).leading_zeros(8);
+    }
+
+    #[test]
+    fn test_trailing_zeros() {
+        assert_eq!(UInt::new([]).trailing_zeros(), None);
+        assert_eq!(UInt::from(0u64).trailing_zeros(), None);
+        for x in [1u64, 0b1000, 0x1234_0000, 1 << 63] {
+            assert_eq!(
+                UInt::from(x).trailing_zeros(),
+                Some(x.trailing_zeros() as usize)
+            );
+        }
+        assert_eq!((UInt::from(3u64) << 200usize).trailing_zeros(), Some(200));
+    }
+
+    #[test]
+    fn test_power_of_two() {
+        assert!(!UInt::from(0u64).is_power_of_two());
+        assert!(UInt::from(1u64).is_power_of_two());
+        assert!(!UInt::from(6u64).is_power_of_two());
+        assert!((UInt::from(1u64) << 300usize).is_power_of_two());
+        for x in [0u64, 1, 2, 3, 5, 64, 100, 1 << 62] {
+            assert_eq!(
+                UInt::from(x).next_power_of_two(),
+                UInt::from(x.next_power_of_two())
+            );
+        }
+        let big = (UInt::from(1u64) << 100usize) + 1u64;
+        assert_eq!(big.next_power_of_two(), UInt::from(1u64) << 101usize);
+    }
+
+    #[test]
+    fn test_ilog2() {
+        for x in [1u64, 2, 3, 1000, u64::MAX] {
+            assert_eq!(UInt::from(x).ilog2(), x.ilog2() as usize);
+        }
+        assert_eq!((UInt::from(5u64) << 1000usize).ilog2(), 1002);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ilog2_zero() {
+        let _ = UInt::from(0u64).ilog2();
+    }
+}
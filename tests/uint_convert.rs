@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod uint_convert {
+    use binum::{Boolean, Int, UInt};
+    use Boolean::*;
+
+    #[test]
+    fn test_from_unsigned() {
+        assert_eq!(UInt::from(0u8), UInt::new([]));
+        assert_eq!(UInt::from(u8::MAX), UInt::new([True; 8]));
+        assert_eq!(UInt::from(u16::MAX), UInt::new([True; 16]));
+        assert_eq!(UInt::from(u32::MAX), UInt::new([True; 32]));
+        assert_eq!(UInt::from(u128::MAX), UInt::new([True; 128]));
+        assert_eq!(
+            UInt::from(usize::MAX),
+            UInt::new(vec![True; usize::BITS as usize])
+        );
+        assert_eq!(UInt::from(0x1234u16), UInt::from(0x1234u64));
+        assert_eq!(UInt::from(0x1234u128), UInt::from(0x1234usize));
+    }
+
+    #[test]
+    fn test_try_from_signed() {
+        assert_eq!(UInt::try_from(0i8), Ok(UInt::new([])));
+        assert_eq!(UInt::try_from(i8::MAX), Ok(UInt::new([True; 7])));
+        assert_eq!(UInt::try_from(i64::MAX), Ok(UInt::new([True; 63])));
+        assert_eq!(UInt::try_from(i128::MAX), Ok(UInt::new([True; 127])));
+        assert_eq!(UInt::try_from(42isize), Ok(UInt::from(42u64)));
+        assert!(UInt::try_from(-1i8).is_err());
+        assert!(UInt::try_from(i32::MIN).is_err());
+        assert!(UInt::try_from(-1i128).is_err());
+        assert!(UInt::try_from(-1isize).is_err());
+    }
+
+    #[test]
+    fn test_try_into_unsigned() {
+        assert_eq!(u8::try_from(UInt::from(255u64)), Ok(255));
+        assert!(u8::try_from(UInt::from(256u64)).is_err());
+        assert_eq!(u16::try_from(UInt::from(u16::MAX)), Ok(u16::MAX));
+        assert!(u16::try_from(UInt::from(1u64 << 16)).is_err());
+        assert_eq!(u32::try_from(UInt::from(u32::MAX)), Ok(u32::MAX));
+        assert_eq!(u64::try_from(UInt::from(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(u128::try_from(UInt::from(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(usize::try_from(UInt::from(7usize)), Ok(7));
+        // bits beyond 64 are reported instead of being dropped
+        let n = UInt::from(u64::MAX) + UInt::from(1u64);
+        assert!(u64::try_from(n.clone()).is_err());
+        assert_eq!(u128::try_from(n), Ok(1 << 64));
+        let n = UInt::new([True; 129]);
+        assert!(u128::try_from(n).is_err());
+        // leading False bits do not count
+        let mut bin = vec![True; 8];
+        bin.extend([False; 100]);
+        assert_eq!(u8::try_from(UInt::new(bin)), Ok(u8::MAX));
+        assert_eq!(u8::try_from(UInt::new([])), Ok(0));
+    }
+
+    #[test]
+    fn test_try_into_signed() {
+        assert_eq!(i8::try_from(UInt::from(127u64)), Ok(127));
+        assert!(i8::try_from(UInt::from(128u64)).is_err());
+        assert_eq!(i64::try_from(UInt::from(i64::MAX as u64)), Ok(i64::MAX));
+        assert!(i64::try_from(UInt::from(1u64 << 63)).is_err());
+        assert_eq!(i128::try_from(UInt::from(u64::MAX)), Ok(u64::MAX as i128));
+        assert_eq!(isize::try_from(UInt::new([])), Ok(0));
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = u8::try_from(UInt::from(256u64)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "out of range integral type conversion attempted"
+        );
+    }
+
+    #[test]
+    fn test_int_from_primitive() {
+        assert_eq!(Int::from(-5i8), Int::new(true, UInt::from(5u64)));
+        assert_eq!(
+            Int::from(i128::MIN),
+            Int::new(true, UInt::from(1u128 << 127))
+        );
+        assert_eq!(Int::from(u128::MAX), Int::new(false, UInt::from(u128::MAX)));
+        assert_eq!(Int::from(3usize), Int::from(3isize));
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod uint_fmt {
+    use std::num::IntErrorKind;
+    use std::str::FromStr;
+
+    use binum::{Boolean, Int, UInt};
+    use Boolean::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(UInt::new([]).to_string(), "0");
+        assert_eq!(UInt::new([False; 8]).to_string(), "0");
+        assert_eq!(UInt::from(1u64).to_string(), "1");
+        assert_eq!(UInt::from(1234567890u64).to_string(), "1234567890");
+        assert_eq!(UInt::from(u64::MAX).to_string(), u64::MAX.to_string());
+        // 2^64
+        let n = UInt::from(u64::MAX) + UInt::from(1u64);
+        assert_eq!(n.to_string(), "18446744073709551616");
+        assert_eq!(format!("{:>6}", UInt::from(42u64)), "    42");
+        assert_eq!(format!("{:+}", UInt::from(42u64)), "+42");
+    }
+
+    #[test]
+    fn test_display_int() {
+        assert_eq!(Int::from(0).to_string(), "0");
+        assert_eq!(Int::from(-42).to_string(), "-42");
+        assert_eq!(Int::from(42).to_string(), "42");
+        assert_eq!(format!("{:05}", Int::from(-42)), "-0042");
+    }
+
+    #[test]
+    fn test_radix_formats() {
+        let values = [0, 1, 7, 0b10011011, 0xdead_beef, u64::MAX];
+        for &v in values.iter() {
+            let n = UInt::from(v);
+            assert_eq!(format!("{n:b}"), format!("{v:b}"));
+            assert_eq!(format!("{n:o}"), format!("{v:o}"));
+            assert_eq!(format!("{n:x}"), format!("{v:x}"));
+            assert_eq!(format!("{n:X}"), format!("{v:X}"));
+            assert_eq!(format!("{n:#b}"), format!("{v:#b}"));
+            assert_eq!(format!("{n:#o}"), format!("{v:#o}"));
+            assert_eq!(format!("{n:#x}"), format!("{v:#x}"));
+            assert_eq!(format!("{n:#X}"), format!("{v:#X}"));
+        }
+        // leading False bits are not printed
+        assert_eq!(format!("{:b}", UInt::new([True, False, False])), "1");
+        assert_eq!(format!("{:b}", UInt::new([True; 70])), "1".repeat(70));
+    }
+
+    #[test]
+    fn test_radix_formats_padding() {
+        let n = UInt::from(0x2au64);
+        let v = 0x2au64;
+        assert_eq!(format!("{n:08b}"), format!("{v:08b}"));
+        assert_eq!(format!("{n:#010b}"), format!("{v:#010b}"));
+        assert_eq!(format!("{n:#06x}"), format!("{v:#06x}"));
+        assert_eq!(format!("{n:>8X}"), format!("{v:>8X}"));
+        assert_eq!(format!("{n:<8o}|"), format!("{v:<8o}|"));
+        assert_eq!(format!("{n:*^9x}"), format!("{v:*^9x}"));
+        assert_eq!(format!("{n:#3x}"), format!("{v:#3x}"));
+        assert_eq!(format!("{n:+#x}"), format!("{v:+#x}"));
+        assert_eq!(format!("{n:010}"), format!("{v:010}"));
+    }
+
+    #[test]
+    fn test_to_str_radix() {
+        let n = UInt::from(0b10011011u64);
+        assert_eq!(n.to_str_radix(2), "10011011");
+        assert_eq!(n.to_str_radix(8), "233");
+        assert_eq!(n.to_str_radix(16), "9b");
+        assert_eq!(n.to_str_radix(36), "4b");
+        assert_eq!(UInt::new([]).to_str_radix(7), "0");
+        let n = UInt::new([True; 100]);
+        assert_eq!(n.to_str_radix(2), "1".repeat(100));
+        assert_eq!(n.to_str_radix(16), format!("f{}", "f".repeat(24)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_str_radix_fails() {
+        let _ = UInt::from(1u64).to_str_radix(37);
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(UInt::from_str_radix("9b", 16), Ok(UInt::from(0x9bu64)));
+        assert_eq!(UInt::from_str_radix("9B", 16), Ok(UInt::from(0x9bu64)));
+        assert_eq!(UInt::from_str_radix("+233", 8), Ok(UInt::from(0o233u64)));
+        assert_eq!(
+            UInt::from_str_radix("zz", 36),
+            Ok(UInt::from(35u64 * 36 + 35))
+        );
+        assert_eq!(UInt::from_str_radix("000", 10), Ok(UInt::from(0u64)));
+        let err = UInt::from_str_radix("", 10).unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::Empty);
+        let err = UInt::from_str_radix("12", 2).unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::InvalidDigit);
+        let err = UInt::from_str_radix("-1", 10).unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::InvalidDigit);
+        let err = UInt::from_str_radix("+", 10).unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::InvalidDigit);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(UInt::from_str("1234567890"), Ok(UInt::from(1234567890u64)));
+        assert_eq!("0b1011".parse::<UInt>(), Ok(UInt::from(0b1011u64)));
+        assert_eq!("0o777".parse::<UInt>(), Ok(UInt::from(0o777u64)));
+        assert_eq!("0xdeadBEEF".parse::<UInt>(), Ok(UInt::from(0xdeadbeefu64)));
+        assert_eq!("+0x10".parse::<UInt>(), Ok(UInt::from(16u64)));
+        assert_eq!("0".parse::<UInt>(), Ok(UInt::from(0u64)));
+        assert!("0x".parse::<UInt>().is_err());
+        assert!("0x+1".parse::<UInt>().is_err());
+        assert!("0b2".parse::<UInt>().is_err());
+        assert!("12a".parse::<UInt>().is_err());
+        assert!(" 1".parse::<UInt>().is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let s = "340282366920938463463374607431768211457123456789";
+        let n: UInt = s.parse().unwrap();
+        assert_eq!(n.to_string(), s);
+        for radix in 2..=36 {
+            let t = n.to_str_radix(radix);
+            assert_eq!(UInt::from_str_radix(&t, radix), Ok(n.clone()));
+        }
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = "".parse::<UInt>().unwrap_err();
+        assert_eq!(err.to_string(), "cannot parse integer from empty string");
+        let err = "x".parse::<UInt>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit found in string");
+    }
+}
@@ -0,0 +1,123 @@
+mod common;
+
+#[cfg(test)]
+mod uint_modular {
+    use crate::common::XorShift;
+    use binum::{BarrettCtx, MontgomeryCtx, UInt};
+
+    fn moduli(rng: &mut XorShift) -> Vec<UInt> {
+        let mut res = vec![
+            UInt::from(1u64),
+            UInt::from(3u64),
+            UInt::from(u64::MAX),
+            (UInt::from(1u64) << 127usize) - 1u64,
+        ];
+        for limbs in [1, 2, 3, 5] {
+            res.push(rng.next_uint(limbs) | UInt::from(1u64));
+        }
+        res
+    }
+
+    #[test]
+    fn test_montgomery() {
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        for m in moduli(&mut rng) {
+            let ctx = MontgomeryCtx::new(&m);
+            assert_eq!(ctx.modulus(), &m);
+            assert_eq!(ctx.from_montgomery(&ctx.one()), UInt::from(1u64) % &m);
+            for _ in 0..10 {
+                let a = rng.next_uint(4);
+                let b = rng.next_uint(2) % &m;
+                let am = ctx.to_montgomery(&a);
+                let bm = ctx.to_montgomery(&b);
+                assert!(am < m);
+                assert_eq!(ctx.from_montgomery(&am), &a % &m);
+                assert_eq!(ctx.from_montgomery(&ctx.mul(&am, &bm)), &a * &b % &m);
+                assert_eq!(ctx.from_montgomery(&ctx.square(&am)), &a * &a % &m);
+                let e = rng.next_uint(1);
+                let p = ctx.from_montgomery(&ctx.pow(&am, &e));
+                assert_eq!(p, a.pow_mod(&e, &m));
+            }
+        }
+    }
+
+    #[test]
+    fn test_montgomery_unreduced() {
+        // operands at or above the modulus are reduced first
+        let m = UInt::from(1_000_003u64);
+        let ctx = MontgomeryCtx::new(&m);
+        let (a, b) = (UInt::from(u128::MAX), UInt::from(u128::MAX - 5));
+        assert_eq!(
+            ctx.from_montgomery(&ctx.mul(&a, &b)),
+            UInt::from(769_710u64)
+        );
+        let (am, bm) = (ctx.to_montgomery(&a), ctx.to_montgomery(&b));
+        assert_eq!(ctx.from_montgomery(&ctx.mul(&am, &bm)), &a * &b % &m);
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        for m in moduli(&mut rng) {
+            let ctx = MontgomeryCtx::new(&m);
+            let (a, b) = (rng.next_uint(6), rng.next_uint(5));
+            let expected = ctx.from_montgomery(&ctx.mul(&(&a % &m), &(&b % &m)));
+            assert_eq!(ctx.from_montgomery(&ctx.mul(&a, &b)), expected);
+            assert_eq!(
+                ctx.from_montgomery(&ctx.square(&a)),
+                expected_square(&ctx, &a, &m)
+            );
+            assert_eq!(ctx.from_montgomery(&a), ctx.from_montgomery(&(&a % &m)));
+            let e = rng.next_uint(1);
+            let p = ctx.pow(&a, &e);
+            assert_eq!(p, ctx.pow(&(&a % &m), &e));
+            assert!(p < m);
+        }
+    }
+
+    fn expected_square(ctx: &MontgomeryCtx, a: &UInt, m: &UInt) -> UInt {
+        let a = a % m;
+        ctx.from_montgomery(&ctx.mul(&a, &a))
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_montgomery_even() {
+        let _ = MontgomeryCtx::new(&UInt::from(10u64));
+    }
+
+    #[test]
+    fn test_barrett() {
+        let mut rng = XorShift::new(0x9e37_79b9_7f4a_7c15);
+        let mut ms = moduli(&mut rng);
+        ms.push(UInt::from(2u64));
+        ms.push(UInt::from(1u64) << 100usize);
+        ms.push(rng.next_uint(3) << 1usize);
+        for m in ms {
+            let ctx = BarrettCtx::new(&m);
+            assert_eq!(ctx.modulus(), &m);
+            for _ in 0..10 {
+                let a = rng.next_uint(7);
+                let b = rng.next_uint(3);
+                assert_eq!(ctx.reduce(&a), &a % &m);
+                let (a, b) = (a % &m, b % &m);
+                assert_eq!(ctx.mul(&a, &b), &a * &b % &m);
+                assert_eq!(ctx.square(&a), &a * &a % &m);
+                let e = rng.next_uint(1);
+                let expected = (0..e.bit_len())
+                    .rev()
+                    .fold(UInt::from(1u64) % &m, |acc, i| {
+                        let acc = &acc * &acc % &m;
+                        if ((&e >> i) & UInt::from(1u64)) == UInt::from(1u64) {
+                            acc * &a % &m
+                        } else {
+                            acc
+                        }
+                    });
+                assert_eq!(ctx.pow(&a, &e), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_barrett_zero() {
+        let _ = BarrettCtx::new(&UInt::from(0u64));
+    }
+}
@@ -0,0 +1,158 @@
+mod common;
+
+#[cfg(test)]
+mod uint_mul {
+    use crate::common::XorShift;
+    use binum::{MulConfig, UInt};
+
+    // low thresholds so that small operands reach every algorithm
+    const SMALL: MulConfig = MulConfig {
+        karatsuba: 4,
+        toom3: 9,
+        ntt: usize::MAX,
+    };
+
+    fn mul(a: &UInt, b: &UInt) -> UInt {
+        a.mul_with(b, &SMALL)
+    }
+
+    // deterministic pseudo random limbs, one stream per seed
+    fn random_uint(limbs: usize, seed: u64) -> UInt {
+        XorShift::new(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1).next_uint(limbs)
+    }
+
+    // shift-and-add reference multiplication
+    fn reference_mul(a: &UInt, b: &UInt) -> UInt {
+        let mut res = UInt::new([]);
+        for (i, bit) in b.bits().enumerate() {
+            if bit.into() {
+                res += a << i;
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_mul_config() {
+        let config = MulConfig::default();
+        assert_eq!(
+            (config.karatsuba, config.toom3, config.ntt),
+            (32, 256, 20_000)
+        );
+        let a = random_uint(40, 8);
+        let b = random_uint(30, 9);
+        let expected = reference_mul(&a, &b);
+        assert_eq!(a.mul_with(&b, &config), expected);
+        assert_eq!(&a * &b, expected);
+        // each algorithm alone, and the NTT from the first limb on
+        for (karatsuba, toom3, ntt) in [
+            (usize::MAX, 0, usize::MAX),
+            (4, usize::MAX, usize::MAX),
+            (4, 4, usize::MAX),
+            (1, 1, 1),
+        ] {
+            let config = MulConfig {
+                karatsuba,
+                toom3,
+                ntt,
+            };
+            assert_eq!(a.mul_with(&b, &config), expected, "{config:?}");
+        }
+    }
+
+    #[test]
+    fn test_mul_sizes() {
+        let sizes = [1, 3, 4, 5, 8, 9, 10, 17, 28, 40];
+        for (i, &la) in sizes.iter().enumerate() {
+            for (j, &lb) in sizes.iter().enumerate() {
+                let a = random_uint(la, i as u64);
+                let b = random_uint(lb, 100 + j as u64);
+                assert_eq!(mul(&a, &b), reference_mul(&a, &b), "{la} x {lb} limbs");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_all_ones() {
+        // maximal carries everywhere
+        for len in [4, 9, 20, 33] {
+            let a = UInt::from_limbs(vec![u64::MAX; len]);
+            let b = UInt::from_limbs(vec![u64::MAX; len + 1]);
+            // (2^n - 1)(2^m - 1) = 2^(n+m) - 2^n - 2^m + 1
+            let (n, m) = (64 * len, 64 * (len + 1));
+            let one = UInt::from(1u64);
+            let expected = (&one << (n + m)) + 1u64 - (&one << n) - (&one << m);
+            assert_eq!(mul(&a, &b), expected);
+            assert_eq!(mul(&a, &a), reference_mul(&a, &a));
+        }
+    }
+
+    #[test]
+    fn test_mul_sparse() {
+        // operands with zero limbs in the middle and on top of `bit_len`
+        let a = (UInt::from(1u64) << 1000usize) + 1u64;
+        let b = (UInt::from(3u64) << 700usize) + (UInt::from(5u64) << 64usize);
+        assert_eq!(mul(&a, &b), reference_mul(&a, &b));
+        let padded = UInt::from_limbs([7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let c = random_uint(12, 7);
+        assert_eq!(mul(&padded, &c), &c * 7u64);
+        assert_eq!(mul(&c, &UInt::new([])), UInt::new([]));
+    }
+
+    #[test]
+    fn test_mul_large() {
+        let a = random_uint(300, 1);
+        let b = random_uint(250, 2);
+        let c = random_uint(20, 3);
+        let ab = mul(&a, &b);
+        // (a * b) / b == a and distributivity
+        assert_eq!(ab.div_rem(&b), (a.clone(), UInt::new([])));
+        assert_eq!(mul(&a, &(&b + &c)), &ab + mul(&a, &c));
+    }
+
+    #[test]
+    fn test_mul_ntt() {
+        let sizes = [1, 2, 7, 16, 33, 100];
+        for (i, &la) in sizes.iter().enumerate() {
+            for (j, &lb) in sizes.iter().enumerate() {
+                let a = random_uint(la, 10 + i as u64);
+                let b = random_uint(lb, 20 + j as u64);
+                assert_eq!(a.mul_ntt(&b), reference_mul(&a, &b), "{la} x {lb} limbs");
+            }
+        }
+        let a = UInt::from_limbs(vec![u64::MAX; 150]);
+        assert_eq!(a.mul_ntt(&a), reference_mul(&a, &a));
+        assert_eq!(a.mul_ntt(&UInt::new([])), UInt::new([]));
+        assert_eq!(
+            UInt::from(12345u64).mul_ntt(&UInt::from(678u64)),
+            UInt::from(12345u64 * 678)
+        );
+    }
+
+    #[test]
+    fn test_mul_ntt_large() {
+        // 2^20 bits per operand
+        let a = random_uint(1 << 14, 5);
+        let b = random_uint(1 << 14, 6);
+        let c = a.mul_ntt(&b);
+        assert_eq!(c, &b * &a);
+        assert_eq!(c.div_rem(&UInt::from(u64::MAX)).1, {
+            // a * b mod (2^64 - 1) from the limb sums
+            let m = UInt::from(u64::MAX);
+            (&a % &m) * (&b % &m) % &m
+        });
+    }
+
+    #[test]
+    fn test_factorial() {
+        let mut n = UInt::from(1u64);
+        for i in 1..=300u64 {
+            n = n.mul_ntt(&UInt::from(i));
+        }
+        let expected = (1..=300u64).fold(UInt::from(1u64), |acc, i| {
+            reference_mul(&acc, &UInt::from(i))
+        });
+        assert_eq!(n, expected);
+        assert_eq!(n.to_string().len(), 615);
+    }
+}
@@ -6,20 +6,20 @@ mod uint_ops {
     #[test]
     fn test_bitand() {
         // corner case
-        let n1 = UInt::from(0);
+        let n1 = UInt::from(0u64);
         let n2 = UInt::from(u64::MAX);
         assert_eq!(n1.clone() & n1.clone(), UInt::new([]));
         assert_eq!(n1 & n2, UInt::new([]));
         // truth table
-        let n1 = UInt::from(0b0011);
-        let n2 = UInt::from(0b1010);
-        assert_eq!(n1 & n2, UInt::from(0b0010));
+        let n1 = UInt::from(0b0011u64);
+        let n2 = UInt::from(0b1010u64);
+        assert_eq!(n1 & n2, UInt::from(0b0010u64));
         // idempotency, commutativity, associativity
-        let n1 = UInt::from(0b10011011);
+        let n1 = UInt::from(0b10011011u64);
         assert_eq!(n1.clone() & n1.clone(), n1); // idempotency
-        let n2 = UInt::from(0b10100000);
+        let n2 = UInt::from(0b10100000u64);
         assert_eq!(n1.clone() & n2.clone(), n2.clone() & n1.clone()); // commutativity
-        let n3 = UInt::from(0b11000000);
+        let n3 = UInt::from(0b11000000u64);
         assert_eq!((n1.clone() & n2.clone()) & n3.clone(), n1 & (n2 & n3)); // associativity
     }
 
@@ -29,18 +29,18 @@ mod uint_ops {
         let mut n1 = UInt::new([]);
         let mut n2 = UInt::from(u64::MAX);
         n1 &= n1.clone();
-        assert_eq!(n1, UInt::from(0));
+        assert_eq!(n1, UInt::from(0u64));
         n2 &= n1;
-        assert_eq!(n2, UInt::from(0));
+        assert_eq!(n2, UInt::from(0u64));
         // truth table
-        let mut n1 = UInt::from(0b0011);
-        let n2 = UInt::from(0b1010);
+        let mut n1 = UInt::from(0b0011u64);
+        let n2 = UInt::from(0b1010u64);
         n1 &= n2;
-        assert_eq!(n1, UInt::from(0b0010));
+        assert_eq!(n1, UInt::from(0b0010u64));
         // idempotency, commutativity, associativity
-        let n1 = UInt::from(0b10011011);
-        let n2 = UInt::from(0b10100000);
-        let n3 = UInt::from(0b11000000);
+        let n1 = UInt::from(0b10011011u64);
+        let n2 = UInt::from(0b10100000u64);
+        let n3 = UInt::from(0b11000000u64);
         let mut n4 = n1.clone();
         n4 &= n4.clone();
         assert_eq!(n4, n1); // idempotency
@@ -66,15 +66,15 @@ mod uint_ops {
         assert_eq!(n1.clone() | n1.clone(), UInt::new([]));
         assert_eq!(n1 | n2, UInt::new([True; u64::BITS as usize]));
         // truth table
-        let n1 = UInt::from(0b0011);
-        let n2 = UInt::from(0b1010);
-        assert_eq!(n1 | n2, UInt::from(0b1011));
+        let n1 = UInt::from(0b0011u64);
+        let n2 = UInt::from(0b1010u64);
+        assert_eq!(n1 | n2, UInt::from(0b1011u64));
         // idempotency, commutativity, associativity
-        let n1 = UInt::from(0b10011011);
+        let n1 = UInt::from(0b10011011u64);
         assert_eq!(n1.clone() | n1.clone(), n1); // idempotency
-        let n2 = UInt::from(0b10100000);
+        let n2 = UInt::from(0b10100000u64);
         assert_eq!(n1.clone() | n2.clone(), n2.clone() | n1.clone()); // commutativity
-        let n3 = UInt::from(0b11000000);
+        let n3 = UInt::from(0b11000000u64);
         assert_eq!((n1.clone() | n2.clone()) | n3.clone(), n1 | (n2 | n3)); // associativity
     }
 
@@ -88,14 +88,14 @@ mod uint_ops {
         n2 |= n1;
         assert_eq!(n2, UInt::new([True; u64::BITS as usize]));
         // truth table
-        let mut n1 = UInt::from(0b0011);
-        let n2 = UInt::from(0b1010);
+        let mut n1 = UInt::from(0b0011u64);
+        let n2 = UInt::from(0b1010u64);
         n1 |= n2;
-        assert_eq!(n1, UInt::from(0b1011));
+        assert_eq!(n1, UInt::from(0b1011u64));
         // idempotency, commutativity, associativity
-        let n1 = UInt::from(0b10011011);
-        let n2 = UInt::from(0b10100000);
-        let n3 = UInt::from(0b11000000);
+        let n1 = UInt::from(0b10011011u64);
+        let n2 = UInt::from(0b10100000u64);
+        let n3 = UInt::from(0b11000000u64);
         let mut n4 = n1.clone();
         n4 |= n4.clone();
         assert_eq!(n4, n1); // idempotency
@@ -116,19 +116,19 @@ mod uint_ops {
     #[test]
     fn test_bitxor() {
         // corner case
-        let n1 = UInt::from(0);
+        let n1 = UInt::from(0u64);
         let n2 = UInt::from(u64::MAX);
         assert_eq!(n1.clone() ^ n1.clone(), UInt::new([]));
         assert_eq!(n1 ^ n2, UInt::new([True; u64::BITS as usize]));
         // truth table
-        let n1 = UInt::from(0b0011);
-        let n2 = UInt::from(0b1010);
-        assert_eq!(n1 ^ n2, UInt::from(0b1001));
+        let n1 = UInt::from(0b0011u64);
+        let n2 = UInt::from(0b1010u64);
+        assert_eq!(n1 ^ n2, UInt::from(0b1001u64));
         // commutativity, associativity
-        let n1 = UInt::from(0b10011011);
-        let n2 = UInt::from(0b10100000);
+        let n1 = UInt::from(0b10011011u64);
+        let n2 = UInt::from(0b10100000u64);
         assert_eq!(n1.clone() ^ n2.clone(), n2.clone() ^ n1.clone()); // commutativity
-        let n3 = UInt::from(0b11000000);
+        let n3 = UInt::from(0b11000000u64);
         assert_eq!((n1.clone() ^ n2.clone()) ^ n3.clone(), n1 ^ (n2 ^ n3)); // associativity
     }
 
@@ -138,18 +138,18 @@ mod uint_ops {
         let mut n1 = UInt::new([]);
         let mut n2 = UInt::from(u64::MAX);
         n1 ^= n1.clone();
-        assert_eq!(n1, UInt::from(0));
+        assert_eq!(n1, UInt::from(0u64));
         n2 ^= n1;
         assert_eq!(n2, UInt::new([True; u64::BITS as usize]));
         // truth table
-        let mut n1 = UInt::from(0b0011);
-        let n2 = UInt::from(0b1010);
+        let mut n1 = UInt::from(0b0011u64);
+        let n2 = UInt::from(0b1010u64);
         n1 ^= n2;
-        assert_eq!(n1, UInt::from(0b1001));
+        assert_eq!(n1, UInt::from(0b1001u64));
         // commutativity, associativity
-        let n1 = UInt::from(0b10011011);
-        let n2 = UInt::from(0b10100000);
-        let n3 = UInt::from(0b11000000);
+        let n1 = UInt::from(0b10011011u64);
+        let n2 = UInt::from(0b10100000u64);
+        let n3 = UInt::from(0b11000000u64);
         let mut n4 = n1.clone();
         let mut n5 = n2.clone();
         n4 ^= n5.clone();
@@ -168,9 +168,9 @@ mod uint_ops {
     #[test]
     fn test_shl() {
         // basics
-        assert_eq!(UInt::from(3) << UInt::from(2), UInt::from(12));
+        assert_eq!(UInt::from(3u64) << UInt::from(2u64), UInt::from(12u64));
         // corner case
-        let left = UInt::from(1) << UInt::from(u64::BITS as u64);
+        let left = UInt::from(1u64) << UInt::from(u64::BITS as u64);
         let right = UInt::new(
             format!("1{:>064}", 0)
                 .chars()
@@ -180,19 +180,19 @@ mod uint_ops {
         );
         assert_eq!(left, right);
         // unity
-        let unit = UInt::from(0);
-        let n = UInt::from(9);
+        let unit = UInt::from(0u64);
+        let n = UInt::from(9u64);
         assert_eq!(n.clone() << unit, n);
     }
 
     #[test]
     fn test_shl_assign() {
         // basics
-        let mut n = UInt::from(3);
-        n <<= UInt::from(2);
-        assert_eq!(n, UInt::from(12));
+        let mut n = UInt::from(3u64);
+        n <<= UInt::from(2u64);
+        assert_eq!(n, UInt::from(12u64));
         // corner case
-        let mut left = UInt::from(1);
+        let mut left = UInt::from(1u64);
         left <<= UInt::from(u64::BITS as u64);
         let right = UInt::new(
             format!("1{:>064}", 0)
@@ -203,41 +203,44 @@ mod uint_ops {
         );
         assert_eq!(left, right);
         // unity
-        let unit = UInt::from(0);
-        let mut n = UInt::from(9);
+        let unit = UInt::from(0u64);
+        let mut n = UInt::from(9u64);
         n <<= unit;
-        assert_eq!(n, UInt::from(9));
+        assert_eq!(n, UInt::from(9u64));
     }
 
     #[test]
     fn test_shr() {
         // basics
-        assert_eq!(UInt::from(0b1101) >> UInt::from(2), UInt::from(0b11));
+        assert_eq!(
+            UInt::from(0b1101u64) >> UInt::from(2u64),
+            UInt::from(0b11u64)
+        );
         // corner case
-        let left = UInt::from(1) >> UInt::from(10);
-        let right = UInt::from(0);
+        let left = UInt::from(1u64) >> UInt::from(10u64);
+        let right = UInt::from(0u64);
         assert_eq!(left, right);
         // unity
-        let unit = UInt::from(0);
-        let n = UInt::from(9);
+        let unit = UInt::from(0u64);
+        let n = UInt::from(9u64);
         assert_eq!(n.clone() >> unit, n);
     }
 
     #[test]
     fn test_shr_assign() {
         // basics
-        let mut n = UInt::from(0b1101);
-        n >>= UInt::from(2);
-        assert_eq!(n, UInt::from(0b11));
+        let mut n = UInt::from(0b1101u64);
+        n >>= UInt::from(2u64);
+        assert_eq!(n, UInt::from(0b11u64));
         // corner case
-        let mut left = UInt::from(1);
-        left >>= UInt::from(10);
-        let right = UInt::from(0);
+        let mut left = UInt::from(1u64);
+        left >>= UInt::from(10u64);
+        let right = UInt::from(0u64);
         assert_eq!(left, right);
         // unity
-        let unit = UInt::from(0);
-        let mut n = UInt::from(9);
+        let unit = UInt::from(0u64);
+        let mut n = UInt::from(9u64);
         n >>= unit;
-        assert_eq!(n, UInt::from(9));
+        assert_eq!(n, UInt::from(9u64));
     }
 }
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod uint_ops_checked {
+    use binum::UInt;
+
+    const VALUES: [u8; 9] = [0, 1, 2, 7, 0x80, 0x9b, 0xa0, 0xfe, 0xff];
+
+    #[test]
+    fn test_checked() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (UInt::from(a), UInt::from(b));
+                let (wa, wb) = (a as u64, b as u64);
+                assert_eq!(x.checked_add(&y), Some(UInt::from(wa + wb)));
+                assert_eq!(x.checked_sub(&y), a.checked_sub(b).map(UInt::from));
+                assert_eq!(x.checked_mul(&y), Some(UInt::from(wa * wb)));
+                assert_eq!(x.checked_div(&y), a.checked_div(b).map(UInt::from));
+                assert_eq!(x.checked_rem(&y), a.checked_rem(b).map(UInt::from));
+                assert_eq!(
+                    x.checked_div_rem(&y),
+                    a.checked_div(b)
+                        .zip(a.checked_rem(b))
+                        .map(|(q, r)| (UInt::from(q), UInt::from(r)))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_unary() {
+        assert_eq!(UInt::from(0u64).checked_neg(), Some(UInt::new([])));
+        assert_eq!(UInt::from(1u64).checked_neg(), None);
+        let n = UInt::from(0b1011u64);
+        assert_eq!(n.checked_shl(70), Some(UInt::from(0b1011u128 << 70)));
+        assert_eq!(n.checked_shl(usize::MAX), None);
+        assert_eq!(n.checked_shl(usize::MAX - 3), None);
+        assert_eq!(
+            n.checked_shl(1 << 20).map(|m| m.max_bit_digit()),
+            Some(4 + (1 << 20))
+        );
+        assert_eq!(
+            UInt::from(0u64).checked_shl(usize::MAX),
+            Some(UInt::new([]))
+        );
+        assert_eq!(n.checked_shr(2), Some(UInt::from(0b10u64)));
+        assert_eq!(n.checked_shr(usize::MAX), Some(UInt::new([])));
+    }
+
+    #[test]
+    fn test_saturating() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (UInt::from(a), UInt::from(b));
+                let (wa, wb) = (a as u64, b as u64);
+                assert_eq!(x.saturating_add(&y), UInt::from(wa + wb));
+                assert_eq!(x.saturating_sub(&y), UInt::from(a.saturating_sub(b)));
+                assert_eq!(x.saturating_mul(&y), UInt::from(wa * wb));
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflowing() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (UInt::from(a), UInt::from(b));
+                let (res, overflow) = x.overflowing_add(&y, 8);
+                assert_eq!(
+                    (res.clone(), overflow),
+                    (UInt::from(a.wrapping_add(b)), a.overflowing_add(b).1)
+                );
+                assert_eq!(res.bit_len(), 8);
+                let (res, overflow) = x.overflowing_sub(&y, 8);
+                assert_eq!(
+                    (res.clone(), overflow),
+                    (UInt::from(a.wrapping_sub(b)), a.overflowing_sub(b).1)
+                );
+                assert_eq!(res.bit_len(), 8);
+                let (res, overflow) = x.overflowing_mul(&y, 8);
+                assert_eq!(
+                    (res.clone(), overflow),
+                    (UInt::from(a.wrapping_mul(b)), a.overflowing_mul(b).1)
+                );
+                assert_eq!(res.bit_len(), 8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrapping() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (UInt::from(a), UInt::from(b));
+                assert_eq!(x.wrapping_add(&y, 8), UInt::from(a.wrapping_add(b)));
+                assert_eq!(x.wrapping_sub(&y, 8), UInt::from(a.wrapping_sub(b)));
+                assert_eq!(x.wrapping_mul(&y, 8), UInt::from(a.wrapping_mul(b)));
+            }
+        }
+        // an unbounded sum or product never wraps at a wide enough width
+        let (x, y) = (UInt::from(5u64), UInt::from(3u64));
+        assert_eq!(x.overflowing_add(&y, 64), (UInt::from(8u64), false));
+        assert_eq!(x.overflowing_mul(&y, 64), (UInt::from(15u64), false));
+        assert_eq!(x.overflowing_add(&y, 3), (UInt::from(0u64), true));
+        assert_eq!(x.overflowing_mul(&y, 3), (UInt::from(7u64), true));
+        assert_eq!(
+            UInt::from(1u64).wrapping_sub(&UInt::from(3u64), 64),
+            UInt::from(u64::MAX - 1)
+        );
+        assert_eq!(
+            UInt::from(1u64).overflowing_sub(&UInt::from(3u64), 128),
+            (UInt::from(u128::MAX - 1), true)
+        );
+        assert_eq!(
+            UInt::from(300u64).overflowing_sub(&UInt::from(3u64), 8),
+            (UInt::from(297u64 % 256), false)
+        );
+        assert_eq!(
+            UInt::from(u64::MAX).wrapping_add(&UInt::from(1u64), 64),
+            UInt::new([])
+        );
+        assert_eq!(
+            (UInt::from(1u64) << 100usize).wrapping_sub(&UInt::from(1u64), 0),
+            UInt::new([])
+        );
+    }
+}
@@ -6,41 +6,41 @@ mod uint_ops_int {
     #[test]
     fn test_add() {
         // basic
-        assert_eq!(UInt::from(9) + UInt::from(16), UInt::from(25));
+        assert_eq!(UInt::from(9u64) + UInt::from(16u64), UInt::from(25u64));
         // corner case
-        let n0 = UInt::from(0);
+        let n0 = UInt::from(0u64);
         let n1 = UInt::from(u64::MAX);
         assert_eq!(n0.clone() + n0.clone(), UInt::new([]));
         assert_eq!(n0.clone() + n1, UInt::new([True; u64::BITS as usize]));
         // unity, commutativity, associativity
-        let n1 = UInt::from(0b10011011);
+        let n1 = UInt::from(0b10011011u64);
         // unity
         assert_eq!(n1.clone() + n0.clone(), n0.clone() + n1.clone());
         assert_eq!(n1.clone() + n0, n1);
-        let n2 = UInt::from(0b10100000);
+        let n2 = UInt::from(0b10100000u64);
         assert_eq!(n1.clone() + n2.clone(), n2.clone() + n1.clone()); // commutativity
-        let n3 = UInt::from(0b11000000);
+        let n3 = UInt::from(0b11000000u64);
         assert_eq!((n1.clone() + n2.clone()) + n3.clone(), n1 + (n2 + n3)); // associativity
     }
 
     #[test]
     fn test_add_assign() {
         // basic
-        let mut n1 = UInt::from(9);
-        n1 += UInt::from(16);
-        assert_eq!(n1, UInt::from(25));
+        let mut n1 = UInt::from(9u64);
+        n1 += UInt::from(16u64);
+        assert_eq!(n1, UInt::from(25u64));
         // corner case
         let mut n0 = UInt::new([]);
         let mut n1 = UInt::from(u64::MAX);
         n0 += n0.clone();
-        assert_eq!(n0, UInt::from(0));
+        assert_eq!(n0, UInt::from(0u64));
         n1 += n0;
         assert_eq!(n1, UInt::new([True; u64::BITS as usize]));
         // unity, commutativity, associativity
-        let unit = UInt::from(0);
-        let n1 = UInt::from(0b10011011);
-        let n2 = UInt::from(0b10100000);
-        let n3 = UInt::from(0b11000000);
+        let unit = UInt::from(0u64);
+        let n1 = UInt::from(0b10011011u64);
+        let n2 = UInt::from(0b10100000u64);
+        let n3 = UInt::from(0b11000000u64);
         let mut n4 = n1.clone();
         let mut n5 = unit.clone();
         n4 += unit;
@@ -66,42 +66,42 @@ mod uint_ops_int {
     #[test]
     fn test_mul() {
         // basic
-        assert_eq!(UInt::from(9) * UInt::from(6), UInt::from(54));
+        assert_eq!(UInt::from(9u64) * UInt::from(6u64), UInt::from(54u64));
         // corner case
-        let n0 = UInt::from(0);
+        let n0 = UInt::from(0u64);
         let n1 = UInt::from(u64::MAX);
         assert_eq!(n0.clone() * n0.clone(), UInt::new([]));
         assert_eq!(n0 * n1, UInt::new([]));
         // unity, commutativity, associativity
-        let n1 = UInt::from(1);
-        let n2 = UInt::from(0b10011011);
+        let n1 = UInt::from(1u64);
+        let n2 = UInt::from(0b10011011u64);
         // unity
         assert_eq!(n2.clone() * n1.clone(), n1.clone() * n2.clone());
         assert_eq!(n2.clone() * n1, n2);
-        let n3 = UInt::from(0b10100000);
+        let n3 = UInt::from(0b10100000u64);
         assert_eq!(n2.clone() * n3.clone(), n3.clone() * n2.clone()); // commutativity
-        let n4 = UInt::from(0b11000000);
+        let n4 = UInt::from(0b11000000u64);
         assert_eq!((n2.clone() * n3.clone()) * n4.clone(), n2 * (n3 * n4)); // associativity
     }
 
     #[test]
     fn test_mul_assign() {
         // basic
-        let mut n1 = UInt::from(9);
-        n1 *= UInt::from(6);
-        assert_eq!(n1, UInt::from(54));
+        let mut n1 = UInt::from(9u64);
+        n1 *= UInt::from(6u64);
+        assert_eq!(n1, UInt::from(54u64));
         // corner case
         let mut n0 = UInt::new([]);
         let mut n1 = UInt::from(u64::MAX);
         n0 *= n0.clone();
-        assert_eq!(n0, UInt::from(0));
+        assert_eq!(n0, UInt::from(0u64));
         n1 *= n0;
         assert_eq!(n1, UInt::new([]));
         // unity, commutativity, associativity
-        let unit = UInt::from(1);
-        let n1 = UInt::from(0b10011011);
-        let n2 = UInt::from(0b10100000);
-        let n3 = UInt::from(0b11000000);
+        let unit = UInt::from(1u64);
+        let n1 = UInt::from(0b10011011u64);
+        let n2 = UInt::from(0b10100000u64);
+        let n3 = UInt::from(0b11000000u64);
         let mut n4 = n1.clone();
         let mut n5 = unit.clone();
         n4 *= unit;
@@ -127,16 +127,16 @@ mod uint_ops_int {
     #[test]
     fn test_sub() {
         // basic
-        assert_eq!(UInt::from(16) - UInt::from(9), UInt::from(7));
-        assert_eq!(UInt::from(1) - UInt::from(1), UInt::from(0));
+        assert_eq!(UInt::from(16u64) - UInt::from(9u64), UInt::from(7u64));
+        assert_eq!(UInt::from(1u64) - UInt::from(1u64), UInt::from(0u64));
         // corner case
-        let n0 = UInt::from(0);
+        let n0 = UInt::from(0u64);
         let n1 = UInt::from(u64::MAX);
         assert_eq!(n0.clone() - n0.clone(), UInt::new([]));
         assert_eq!(n1 - n0.clone(), UInt::new([True; u64::BITS as usize]));
         // unity
-        let unit = UInt::from(0);
-        let n1 = UInt::from(0b10011011);
+        let unit = UInt::from(0u64);
+        let n1 = UInt::from(0b10011011u64);
         assert_eq!(n1.clone() - unit, n1);
         // inverse
         assert_eq!(n1.clone() - n1, n0);
@@ -146,27 +146,27 @@ mod uint_ops_int {
     #[should_panic]
     fn test_sub_fails() {
         // this should cause panic
-        let _ = UInt::from(9) - UInt::from(16);
+        let _ = UInt::from(9u64) - UInt::from(16u64);
     }
 
     #[test]
     fn test_sub_assign() {
         // basic
-        let mut n1 = UInt::from(16);
-        n1 -= UInt::from(9);
-        assert_eq!(n1, UInt::from(7));
+        let mut n1 = UInt::from(16u64);
+        n1 -= UInt::from(9u64);
+        assert_eq!(n1, UInt::from(7u64));
         // corner case
-        let mut n0 = UInt::from(0);
+        let mut n0 = UInt::from(0u64);
         let mut n1 = UInt::from(u64::MAX);
         n0 -= n0.clone();
         assert_eq!(n0, UInt::new([]));
         n1 -= n0;
         assert_eq!(n1, UInt::new([True; u64::BITS as usize]));
         // unity
-        let unit = UInt::from(0);
-        let mut n1 = UInt::from(0b10011011);
+        let unit = UInt::from(0u64);
+        let mut n1 = UInt::from(0b10011011u64);
         n1 -= unit.clone();
-        assert_eq!(n1, UInt::from(0b10011011));
+        assert_eq!(n1, UInt::from(0b10011011u64));
         // inverse
         n1 -= n1.clone();
         assert_eq!(n1, unit);
@@ -175,22 +175,22 @@ mod uint_ops_int {
     #[test]
     #[should_panic]
     fn test_sub_assign_fails() {
-        let mut n1 = UInt::from(9);
-        n1 -= UInt::from(16);
+        let mut n1 = UInt::from(9u64);
+        n1 -= UInt::from(16u64);
     }
 
     #[test]
     fn test_div() {
         // basic
-        assert_eq!(UInt::from(55) / UInt::from(9), UInt::from(6));
+        assert_eq!(UInt::from(55u64) / UInt::from(9u64), UInt::from(6u64));
         // corner case
-        let n0 = UInt::from(1);
+        let n0 = UInt::from(1u64);
         let n1 = UInt::from(u64::MAX);
         assert_eq!(n0.clone() / n0.clone(), UInt::new([True]));
         assert_eq!(n1 / n0, UInt::new([True; u64::BITS as usize]));
         // unity
-        let unit = UInt::from(1);
-        let n2 = UInt::from(0b10011011);
+        let unit = UInt::from(1u64);
+        let n2 = UInt::from(0b10011011u64);
         assert_eq!(n2.clone() / unit.clone(), n2);
         // inverse
         assert_eq!(n2.clone() / n2, unit);
@@ -200,27 +200,27 @@ mod uint_ops_int {
     #[should_panic]
     fn test_div_fails() {
         // this should cause ZeroDivisionError
-        let _ = UInt::from(1) / UInt::from(0);
+        let _ = UInt::from(1u64) / UInt::from(0u64);
     }
 
     #[test]
     fn test_div_assign() {
         // basic
-        let mut n1 = UInt::from(16);
-        n1 /= UInt::from(9);
-        assert_eq!(n1, UInt::from(1));
+        let mut n1 = UInt::from(16u64);
+        n1 /= UInt::from(9u64);
+        assert_eq!(n1, UInt::from(1u64));
         // corner case
-        let mut n1 = UInt::from(1);
+        let mut n1 = UInt::from(1u64);
         let mut n2 = UInt::from(u64::MAX);
         n1 /= n1.clone();
         assert_eq!(n1, UInt::new([True]));
         n2 /= n1;
         assert_eq!(n2, UInt::new([True; u64::BITS as usize]));
         // unity
-        let unit = UInt::from(1);
-        let mut n1 = UInt::from(0b10011011);
+        let unit = UInt::from(1u64);
+        let mut n1 = UInt::from(0b10011011u64);
         n1 /= unit.clone();
-        assert_eq!(n1, UInt::from(0b10011011));
+        assert_eq!(n1, UInt::from(0b10011011u64));
         // inverse
         n1 /= n1.clone();
         assert_eq!(n1, unit);
@@ -229,56 +229,135 @@ mod uint_ops_int {
     #[test]
     #[should_panic]
     fn test_div_assign_fails() {
-        let mut n1 = UInt::from(1);
+        let mut n1 = UInt::from(1u64);
         // ZeroDivisionError
-        n1 /= UInt::from(0);
+        n1 /= UInt::from(0u64);
     }
 
     #[test]
     fn test_rem() {
         // basic
-        assert_eq!(UInt::from(16) % UInt::from(9), UInt::from(7));
+        assert_eq!(UInt::from(16u64) % UInt::from(9u64), UInt::from(7u64));
         // corner case
-        let n0 = UInt::from(1);
+        let n0 = UInt::from(1u64);
         let n1 = UInt::from(u64::MAX);
         assert_eq!(n0.clone() % n0.clone(), UInt::new([]));
         assert_eq!(n1 % n0, UInt::new([]));
         // (maybe) inverse
-        let n1 = UInt::from(0b10011011);
-        assert_eq!(n1.clone() % n1, UInt::from(0));
+        let n1 = UInt::from(0b10011011u64);
+        assert_eq!(n1.clone() % n1, UInt::from(0u64));
     }
 
     #[test]
     #[should_panic]
     fn test_rem_fails() {
         // ZeroDivisionError
-        let _ = UInt::from(16) % UInt::from(0);
+        let _ = UInt::from(16u64) % UInt::from(0u64);
     }
 
     #[test]
     fn test_rem_assign() {
         // basic
-        let mut n1 = UInt::from(16);
-        n1 %= UInt::from(9);
-        assert_eq!(n1, UInt::from(7));
+        let mut n1 = UInt::from(16u64);
+        n1 %= UInt::from(9u64);
+        assert_eq!(n1, UInt::from(7u64));
         // corner case
-        let mut n1 = UInt::from(1);
+        let mut n1 = UInt::from(1u64);
         let mut n2 = UInt::from(u64::MAX);
         n1 %= n1.clone();
         assert_eq!(n1, UInt::new([]));
-        n2 %= UInt::from(1);
+        n2 %= UInt::from(1u64);
         assert_eq!(n2, UInt::new([]));
         // (maybe) inverse
-        let mut n1 = UInt::from(0b10011011);
+        let mut n1 = UInt::from(0b10011011u64);
         n1 %= n1.clone();
-        assert_eq!(n1, UInt::from(0));
+        assert_eq!(n1, UInt::from(0u64));
     }
 
     #[test]
     #[should_panic]
     fn test_rem_assign_fails() {
         // ZeroDivisionError
-        let mut n1 = UInt::from(15);
-        n1 %= UInt::from(0);
+        let mut n1 = UInt::from(15u64);
+        n1 %= UInt::from(0u64);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let pairs: [(u128, u128); 8] = [
+            (0, 1),
+            (1, 1),
+            (55, 9),
+            (9, 55),
+            (u64::MAX as u128, 1),
+            (u128::MAX, 3),
+            (u128::MAX, u64::MAX as u128 + 2),
+            (
+                0x1234_5678_9abc_def0_1234_5678_9abc_def0,
+                0xfedc_ba98_7654_3210,
+            ),
+        ];
+        for (a, b) in pairs {
+            let (q, r) = UInt::from(a).div_rem(&UInt::from(b));
+            assert_eq!(q, UInt::from(a / b));
+            assert_eq!(r, UInt::from(a % b));
+            assert_eq!(UInt::from(a) / UInt::from(b), UInt::from(a / b));
+            assert_eq!(UInt::from(a) % UInt::from(b), UInt::from(a % b));
+        }
+        // dividend and divisor wider than 128 bits
+        let b = UInt::from(u128::MAX) * UInt::from(3u64) + UInt::from(1u64);
+        let q = UInt::from(u128::MAX) + UInt::from(12345u64);
+        let r = UInt::from(u128::MAX);
+        let a = q.clone() * b.clone() + r.clone();
+        assert_eq!(a.div_rem(&b), (q, r));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_rem_fails() {
+        let _ = UInt::from(1u64).div_rem(&UInt::new([]));
+    }
+
+    #[test]
+    fn test_sub_large() {
+        let a = UInt::from(1u64) << UInt::from(200u64);
+        let b = UInt::from(1u64);
+        let c = a.clone() - b.clone();
+        assert_eq!(c, UInt::new([True; 200]));
+        assert_eq!(c + b, a);
+        let a = UInt::from(u128::MAX);
+        let b = UInt::from(u64::MAX as u128 + 1);
+        assert_eq!(a - b, UInt::from(u128::MAX - u64::MAX as u128 - 1));
+    }
+
+    #[test]
+    fn test_overflowing_sub() {
+        let padded = |n: u8| {
+            UInt::new(
+                (0..8)
+                    .map(|i| Boolean::from(n >> i & 1 == 1))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let values = [0u8, 1, 2, 7, 0x80, 0x9b, 0xfe, 0xff];
+        for a in values {
+            for b in values {
+                let (res, borrow) = padded(a).overflowing_sub(&padded(b), 8);
+                let (expected, expected_borrow) = a.overflowing_sub(b);
+                assert_eq!(res, UInt::from(expected));
+                assert_eq!(res.bit_len(), 8);
+                assert_eq!(borrow, expected_borrow);
+            }
+        }
+        let (res, borrow) = UInt::from(1u64).overflowing_sub(&UInt::from(2u64), 2);
+        assert_eq!(res, UInt::new([True; 2]));
+        assert!(borrow);
+        let (res, borrow) = UInt::from(u64::MAX).overflowing_sub(&UInt::from(2u64), 64);
+        assert_eq!(res, UInt::from(u64::MAX - 2));
+        assert!(!borrow);
+        let (res, borrow) = UInt::new([]).overflowing_sub(&UInt::from(1u128 << 100), 101);
+        assert_eq!(res, UInt::from(1u128 << 100));
+        assert_eq!(res.bit_len(), 101);
+        assert!(borrow);
     }
 }
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod uint_ops_ref {
+    use binum::UInt;
+
+    #[test]
+    fn test_ref_forms() {
+        let a = UInt::from(0b10011011u64);
+        let b = UInt::from(0b1010u64);
+        let expected = UInt::from(0b10011011u64 + 0b1010);
+        assert_eq!(&a + &b, expected);
+        assert_eq!(&a + b.clone(), expected);
+        assert_eq!(a.clone() + &b, expected);
+        assert_eq!(a.clone() + b.clone(), expected);
+        let mut n = a.clone();
+        n += &b;
+        assert_eq!(n, expected);
+        // operands are still usable
+        assert_eq!(a, UInt::from(0b10011011u64));
+        assert_eq!(b, UInt::from(0b1010u64));
+    }
+
+    #[test]
+    fn test_arith_ref() {
+        let values = [0u64, 1, 7, 0b10011011, 1 << 40, u32::MAX as u64];
+        for &x in values.iter() {
+            for &y in values.iter() {
+                let (a, b) = (UInt::from(x), UInt::from(y));
+                let (wx, wy) = (x as u128, y as u128);
+                assert_eq!(&a + &b, UInt::from(wx + wy));
+                assert_eq!(&a * &b, UInt::from(wx * wy));
+                assert_eq!(&a & &b, UInt::from(x & y));
+                assert_eq!(&a | &b, UInt::from(x | y));
+                assert_eq!(&a ^ &b, UInt::from(x ^ y));
+                if x >= y {
+                    assert_eq!(&a - &b, UInt::from(x - y));
+                }
+                if let (Some(q), Some(r)) = (x.checked_div(y), x.checked_rem(y)) {
+                    assert_eq!(&a / &b, UInt::from(q));
+                    assert_eq!(&a % &b, UInt::from(r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_assign_ref() {
+        let b = UInt::from(6u64);
+        let mut n = UInt::from(9u64);
+        n *= &b;
+        assert_eq!(n, UInt::from(54u64));
+        n -= &b;
+        assert_eq!(n, UInt::from(48u64));
+        n /= &b;
+        assert_eq!(n, UInt::from(8u64));
+        n %= &b;
+        assert_eq!(n, UInt::from(2u64));
+        n |= &b;
+        assert_eq!(n, UInt::from(6u64));
+        n &= &UInt::from(3u64);
+        assert_eq!(n, UInt::from(2u64));
+        n ^= &b;
+        assert_eq!(n, UInt::from(4u64));
+        n <<= &b;
+        assert_eq!(n, UInt::from(256u64));
+        n >>= &UInt::from(4u64);
+        assert_eq!(n, UInt::from(16u64));
+    }
+
+    #[test]
+    fn test_primitive_operands() {
+        let a = UInt::from(100u64);
+        assert_eq!(&a + 5u64, UInt::from(105u64));
+        assert_eq!(a.clone() + 5usize, UInt::from(105u64));
+        assert_eq!(5u64 + &a, UInt::from(105u64));
+        assert_eq!(5usize + a.clone(), UInt::from(105u64));
+        assert_eq!(&a - 1u64, UInt::from(99u64));
+        assert_eq!(200u64 - &a, UInt::from(100u64));
+        assert_eq!(&a * 3usize, UInt::from(300u64));
+        assert_eq!(3u64 * &a, UInt::from(300u64));
+        assert_eq!(&a / 7u64, UInt::from(14u64));
+        assert_eq!(701u64 / &a, UInt::from(7u64));
+        assert_eq!(&a % 7u64, UInt::from(2u64));
+        assert_eq!(701usize % &a, UInt::from(1u64));
+        assert_eq!(&a & 0b1111u64, UInt::from(100u64 & 0b1111));
+        assert_eq!(0b1111u64 | &a, UInt::from(100u64 | 0b1111));
+        assert_eq!(&a ^ 0b1111usize, UInt::from(100u64 ^ 0b1111));
+        let mut n = a.clone();
+        n += 1u64;
+        n *= 2usize;
+        n -= 2u64;
+        n /= 4u64;
+        n %= 30usize;
+        assert_eq!(n, UInt::from(20u64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_primitive_sub_fails() {
+        let _ = 1u64 - UInt::from(2u64);
+    }
+
+    #[test]
+    fn test_shift_forms() {
+        let a = UInt::from(0b1011u64);
+        assert_eq!(&a << 3u64, UInt::from(0b1011000u64));
+        assert_eq!(&a << 3usize, UInt::from(0b1011000u64));
+        assert_eq!(&a << UInt::from(3u64), UInt::from(0b1011000u64));
+        assert_eq!(&a << &UInt::from(3u64), UInt::from(0b1011000u64));
+        assert_eq!(a.clone() << 100usize >> 100u64, a);
+        assert_eq!(&a >> 2usize, UInt::from(0b10u64));
+        assert_eq!(&a >> &UInt::from(2u64), UInt::from(0b10u64));
+        // shifting right by a huge amount clears everything
+        let huge = UInt::from(1u64) << 100usize;
+        assert_eq!(&a >> &huge, UInt::from(0u64));
+        assert_eq!(&a >> u64::MAX, UInt::from(0u64));
+        // zero stays zero however far it is shifted
+        assert_eq!(UInt::from(0u64) << &huge, UInt::from(0u64));
+        let mut n = a.clone();
+        n <<= 64usize;
+        n >>= 63u64;
+        assert_eq!(n, UInt::from(0b10110u64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shl_fails() {
+        let _ = UInt::from(1u64) << usize::MAX;
+    }
+}
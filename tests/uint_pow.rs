@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod uint_pow {
+    use binum::UInt;
+
+    #[test]
+    fn test_pow() {
+        for base in [0u128, 1, 2, 3, 10, 255] {
+            for exp in 0..=15u32 {
+                assert_eq!(UInt::from(base).pow(exp), UInt::from(base.pow(exp)));
+            }
+        }
+        assert_eq!(UInt::from(2u64).pow(200), UInt::from(1u64) << 200usize);
+        assert_eq!(
+            UInt::from(10u64).pow(40).to_string(),
+            format!("1{}", "0".repeat(40))
+        );
+    }
+
+    #[test]
+    fn test_pow_trait() {
+        let n = UInt::from(7u64);
+        let e = UInt::from(20u64);
+        let expected = UInt::from(7u128.pow(20));
+        assert_eq!(binum::Pow::pow(&n, 20u32), expected);
+        assert_eq!(binum::Pow::pow(&n, &e), expected);
+        assert_eq!(binum::Pow::pow(&n, e.clone()), expected);
+        assert_eq!(n.pow_uint(&UInt::from(0u64)), UInt::from(1u64));
+    }
+
+    #[test]
+    fn test_pow_method() {
+        // the inherent method borrows the base for every exponent type
+        let n = UInt::from(7u64);
+        let e = UInt::from(20u64);
+        let expected = UInt::from(7u128.pow(20));
+        assert_eq!(n.pow(20), expected);
+        assert_eq!(n.pow(&e), expected);
+        assert_eq!(n.pow(e), expected);
+    }
+
+    #[test]
+    fn test_pow_glob_import() {
+        // with the trait in scope the base is still only borrowed
+        use binum::*;
+        let n = UInt::from(3u64);
+        let e = UInt::from(5u64);
+        assert_eq!(n.pow(4), UInt::from(81u64));
+        assert_eq!(n.pow(&e), UInt::from(243u64));
+        assert_eq!(n.pow(e.clone()), UInt::from(243u64));
+        assert_eq!(Pow::pow(&n, &e), UInt::from(243u64));
+        assert_eq!(n, UInt::from(3u64));
+        assert_eq!(e, UInt::from(5u64));
+    }
+
+    fn naive_pow_mod(base: u64, exp: u64, modulus: u64) -> u64 {
+        let mut res = 1 % modulus as u128;
+        for _ in 0..exp {
+            res = res * base as u128 % modulus as u128;
+        }
+        res as u64
+    }
+
+    #[test]
+    fn test_pow_mod_small() {
+        for base in [0u64, 1, 2, 7, 12345] {
+            for exp in [0u64, 1, 2, 5, 16, 31, 100] {
+                for modulus in [1u64, 2, 13, 1000, 65537] {
+                    let res = UInt::from(base).pow_mod(&UInt::from(exp), &UInt::from(modulus));
+                    assert_eq!(res, UInt::from(naive_pow_mod(base, exp, modulus)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_mod_large() {
+        let one = UInt::from(1u64);
+        // Fermat's little theorem with Mersenne primes
+        for k in [61usize, 127, 521] {
+            let p = (&one << k) - 1u64;
+            let exp = &p - 1u64;
+            for a in [2u64, 3, 0xdead_beef] {
+                assert_eq!(UInt::from(a).pow_mod(&exp, &p), one);
+            }
+        }
+        // long exponents exercise every window size
+        let m = UInt::from(1_000_000_007u64);
+        let exp = (&one << 1000usize) + 12345u64;
+        let by_pow = UInt::from(3u64).pow_mod(&exp, &m);
+        // 3^(2^1000) * 3^12345 by repeated squaring
+        let mut x = UInt::from(3u64);
+        for _ in 0..1000 {
+            x = &x * &x % &m;
+        }
+        let expected = x * UInt::from(3u64).pow_mod(&UInt::from(12345u64), &m) % &m;
+        assert_eq!(by_pow, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_mod_fails() {
+        let _ = UInt::from(3u64).pow_mod(&UInt::from(2u64), &UInt::new([]));
+    }
+}
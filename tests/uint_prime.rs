@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod uint_prime {
+    use binum::UInt;
+
+    fn sieve(n: usize) -> Vec<bool> {
+        let mut is_prime = vec![true; n];
+        is_prime[0] = false;
+        is_prime[1] = false;
+        for i in 2..n {
+            if is_prime[i] {
+                for j in (i * i..n).step_by(i) {
+                    is_prime[j] = false;
+                }
+            }
+        }
+        is_prime
+    }
+
+    #[test]
+    fn test_small() {
+        let is_prime = sieve(20_000);
+        for (n, &p) in is_prime.iter().enumerate() {
+            let n = UInt::from(n);
+            assert_eq!(n.is_probable_prime(0), p, "{n}");
+            assert_eq!(n.is_bpsw_prime(), p, "{n}");
+        }
+    }
+
+    #[test]
+    fn test_pseudoprimes() {
+        // strong pseudoprimes to base 2, Carmichael numbers and
+        // strong Lucas pseudoprimes
+        let composites = [
+            2047u128,
+            3277,
+            4033,
+            561,
+            1105,
+            5459,
+            5777,
+            10877,
+            3215031751,
+            3825123056546413051,
+            318665857834031151167461,
+        ];
+        for n in composites {
+            let n = UInt::from(n);
+            assert!(!n.is_probable_prime(0), "{n}");
+            assert!(!n.is_bpsw_prime(), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        let one = UInt::from(1u64);
+        for k in [61usize, 89, 107, 127, 521] {
+            let p = (&one << k) - 1u64;
+            assert!(p.is_probable_prime(2), "2^{k} - 1");
+        }
+        for k in [64usize, 67, 128, 257] {
+            let n = (&one << k) - 1u64;
+            assert!(!n.is_probable_prime(2), "2^{k} - 1");
+        }
+        let p = UInt::from(18446744073709551557u64);
+        let q = UInt::from(18446744073709551533u64);
+        assert!(p.is_probable_prime(0));
+        assert!(!(&p * &q).is_probable_prime(4));
+        assert!(!(&p * &p).is_bpsw_prime());
+    }
+
+    #[test]
+    fn test_next_prime() {
+        assert_eq!(UInt::from(0u64).next_prime(), UInt::from(2u64));
+        assert_eq!(UInt::from(2u64).next_prime(), UInt::from(3u64));
+        assert_eq!(UInt::from(3u64).next_prime(), UInt::from(5u64));
+        assert_eq!(UInt::from(24u64).next_prime(), UInt::from(29u64));
+        let one = UInt::from(1u64);
+        assert_eq!((&one << 64usize).next_prime(), (&one << 64usize) + 13u64);
+        assert_eq!((&one << 128usize).next_prime(), (&one << 128usize) + 51u64);
+    }
+
+    #[test]
+    fn test_prev_prime() {
+        assert_eq!(UInt::from(0u64).prev_prime(), None);
+        assert_eq!(UInt::from(2u64).prev_prime(), None);
+        assert_eq!(UInt::from(3u64).prev_prime(), Some(UInt::from(2u64)));
+        assert_eq!(UInt::from(4u64).prev_prime(), Some(UInt::from(3u64)));
+        assert_eq!(UInt::from(30u64).prev_prime(), Some(UInt::from(29u64)));
+        let one = UInt::from(1u64);
+        assert_eq!(
+            (&one << 64usize).prev_prime(),
+            Some((&one << 64usize) - 59u64)
+        );
+        assert_eq!(
+            (&one << 128usize).prev_prime(),
+            Some((&one << 128usize) - 159u64)
+        );
+    }
+}
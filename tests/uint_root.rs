@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod uint_root {
+    use binum::UInt;
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0..2000u64 {
+            let s = UInt::from(n).isqrt();
+            let expected = (0..).take_while(|k| k * k <= n).last().unwrap();
+            assert_eq!(s, UInt::from(expected));
+        }
+        let n = UInt::from(u128::MAX);
+        assert_eq!(n.isqrt(), UInt::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_sqrt_rem_large() {
+        let a = UInt::from(0x1234_5678_9abc_def0_1234_5678_9abc_def0u128).pow(5);
+        for d in [0u64, 1, 12345] {
+            let n = &a * &a + d;
+            let (s, r) = n.sqrt_rem();
+            assert_eq!(s, a);
+            assert_eq!(r, UInt::from(d));
+        }
+        let n = &a * &a - 1u64;
+        let (s, r) = n.sqrt_rem();
+        assert_eq!(s, &a - 1u64);
+        assert_eq!(r, (&a - 1u64) * 2u64);
+    }
+
+    #[test]
+    fn test_is_perfect_square() {
+        let squares: Vec<u64> = (0..100u64).map(|k| k * k).collect();
+        for n in 0..10000u64 {
+            assert_eq!(
+                UInt::from(n).is_perfect_square(),
+                squares.contains(&n),
+                "{n}"
+            );
+        }
+        let a = UInt::from(3u64).pow(101);
+        assert!((&a * &a).is_perfect_square());
+        assert!(!(&a * &a + 1u64).is_perfect_square());
+    }
+
+    #[test]
+    fn test_nth_root() {
+        for n in [0u64, 1, 2, 7, 8, 9, 26, 27, 28, 1000, 1 << 40, u64::MAX] {
+            for k in 1..=10u32 {
+                let r = UInt::from(n).nth_root(k);
+                let r = u64::try_from(r).unwrap() as u128;
+                assert!(r.pow(k) <= n as u128);
+                assert!((r + 1).checked_pow(k).is_none_or(|p| p > n as u128));
+            }
+        }
+        let a = UInt::from(987_654_321u64);
+        for k in [3u32, 5, 17] {
+            let p = a.pow(k);
+            assert_eq!(p.nth_root(k), a);
+            assert_eq!((&p - 1u64).nth_root(k), &a - 1u64);
+            assert_eq!((&p + 1u64).nth_root(k), a);
+        }
+        assert_eq!(UInt::from(5u64).nth_root(100), UInt::from(1u64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nth_root_fails() {
+        let _ = UInt::from(5u64).nth_root(0);
+    }
+}